@@ -0,0 +1,18 @@
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, Error};
+
+mod pointee;
+
+/// Derive `Pointee` for a `#[repr(C)]` or `#[repr(transparent)]` struct whose last field is
+/// dynamically sized.
+///
+/// The pointer metadata and its position are taken from the last field. `DstLayout` is implemented
+/// as well, along with an inherent `layout_for` function that calls into it.
+#[proc_macro_derive(Pointee)]
+pub fn derive_pointee(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    pointee::derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,147 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, LitInt};
+
+/// The representation of the struct being derived.
+struct Repr {
+    align: Option<LitInt>,
+}
+
+impl Repr {
+    fn parse(input: &DeriveInput) -> syn::Result<Repr> {
+        let mut stable = false;
+        let mut align = None;
+
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("repr"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                    stable = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("align") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+
+                    align = Some(content.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported representation for `Pointee`"))
+                }
+            })?;
+        }
+
+        if !stable {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`Pointee` can only be derived for `#[repr(C)]` or `#[repr(transparent)]` structs",
+            ));
+        }
+
+        Ok(Repr { align })
+    }
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let repr = Repr::parse(&input)?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`Pointee` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut types = fields.iter().map(|field| &field.ty);
+
+    let Some(tail) = types.next_back() else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`Pointee` can only be derived for structs with at least one field",
+        ));
+    };
+
+    let prefix: Vec<_> = types.collect();
+
+    let align = repr
+        .align
+        .as_ref()
+        .map(|align| quote!(let layout = layout.align_to(#align)?;));
+
+    let repr_align = repr.align.as_ref().map(|align| {
+        quote! {
+            if #align > align {
+                align = #align;
+            }
+        }
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // The bound is higher-ranked so it is not rejected as trivially false for trait objects.
+    let predicates = where_clause
+        .into_iter()
+        .flat_map(|clause| &clause.predicates);
+    let known_align_where_clause = quote! {
+        where
+            #(#predicates,)*
+            for<'__legume> #tail: ::legume::ptr::KnownAlign,
+    };
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::legume::Pointee for #name #ty_generics #where_clause {
+            type Metadata = <#tail as ::legume::Pointee>::Metadata;
+
+            const METADATA_POSITION: ::legume::ptr::MetadataPosition =
+                <#tail as ::legume::Pointee>::METADATA_POSITION;
+        }
+
+        unsafe impl #impl_generics ::legume::ptr::DstLayout for #name #ty_generics #where_clause {
+            #[inline]
+            fn layout_for(
+                meta: <Self as ::legume::Pointee>::Metadata,
+            ) -> ::core::result::Result<::core::alloc::Layout, ::core::alloc::LayoutError> {
+                let layout = ::core::alloc::Layout::new::<()>();
+                #(let (layout, _) = layout.extend(::core::alloc::Layout::new::<#prefix>())?;)*
+                let (layout, _) = layout.extend(<#tail as ::legume::ptr::DstLayout>::layout_for(meta)?)?;
+                #align
+
+                ::core::result::Result::Ok(layout.pad_to_align())
+            }
+        }
+
+        unsafe impl #impl_generics ::legume::ptr::KnownAlign for #name #ty_generics
+            #known_align_where_clause
+        {
+            const ALIGN: usize = {
+                let mut align = <#tail as ::legume::ptr::KnownAlign>::ALIGN;
+                #(
+                    if ::core::mem::align_of::<#prefix>() > align {
+                        align = ::core::mem::align_of::<#prefix>();
+                    }
+                )*
+                #repr_align
+
+                align
+            };
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Get the layout of `Self` for the given pointer metadata.
+            #[inline]
+            pub fn layout_for(
+                meta: <Self as ::legume::Pointee>::Metadata,
+            ) -> ::core::result::Result<::core::alloc::Layout, ::core::alloc::LayoutError> {
+                <Self as ::legume::ptr::DstLayout>::layout_for(meta)
+            }
+        }
+    })
+}
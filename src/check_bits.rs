@@ -1,16 +1,49 @@
-use core::{convert::Infallible, ptr::NonNull};
+use core::{convert::Infallible, fmt, mem::offset_of, ptr::NonNull};
 
-use crate::{Freeze, Pointee, Zeroable};
+use crate::{macros::impl_for_simd, Freeze, Pointee, Zeroable};
 
-pub unsafe trait AnyBits: CheckBits<Error = Infallible, Bits = Self> + Zeroable {}
+/// Marker trait for types that are valid for any bit pattern.
+///
+/// # Safety
+///
+/// Every initialized bit pattern of `size_of_val(self)` bytes must be a valid `Self`.
+pub unsafe trait AnyBits: CheckBits<Error: Uninhabited, Bits = Self> + Zeroable {}
 
+/// Trait for error types that can never be constructed, such as [`Infallible`].
+pub trait Uninhabited {
+    /// Turn a value that can never exist into any other type.
+    fn unreachable<T>(self) -> T;
+}
+
+impl Uninhabited for Infallible {
+    #[inline]
+    fn unreachable<T>(self) -> T {
+        match self {}
+    }
+}
+
+/// Trait for types that can be validated from some [`AnyBits`] representation.
+///
+/// References, and [`Option`]s of them, do not implement this trait, as no bit pattern can
+/// prove that a pointer is dereferenceable.
+///
+/// # Safety
+///
+/// `Self::Bits` must have the same size and alignment as `Self` for any given metadata,
+/// and every valid `Self` must also be a valid `Self::Bits`.
+///
+/// [`CheckBits::check_bits`] must only return `Ok` if the bits are a valid `Self`.
 pub unsafe trait CheckBits: Pointee {
     type Error;
     type Bits: ?Sized + AnyBits<Metadata = Self::Metadata>;
 
     /// This method does the actual work of validating whether bits are valid.
     ///
-    /// It must NOT mutate any data, and `ptr` must be valid for reads.
+    /// It must NOT mutate any data.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads.
     #[track_caller]
     unsafe fn check_bits(ptr: NonNull<Self::Bits>) -> Result<NonNull<Self>, Self::Error>;
 
@@ -138,6 +171,8 @@ any_bits!(u8, u16, u32, u64, u128, usize);
 any_bits!(i8, i16, i32, i64, i128, isize);
 any_bits!(f32, f64);
 
+impl_for_simd!(any_bits);
+
 unsafe impl CheckBits for bool {
     type Error = ();
     type Bits = u8;
@@ -145,7 +180,7 @@ unsafe impl CheckBits for bool {
     #[inline(always)]
     unsafe fn check_bits(ptr: NonNull<Self::Bits>) -> Result<NonNull<Self>, Self::Error> {
         match unsafe { ptr.read() } {
-            0 | 1 => Ok(ptr.cast()),
+            0..=1 => Ok(ptr.cast()),
             _ => Err(()),
         }
     }
@@ -184,8 +219,275 @@ unsafe impl CheckBits for core::cmp::Ordering {
     #[inline(always)]
     unsafe fn check_bits(ptr: NonNull<Self::Bits>) -> Result<NonNull<Self>, Self::Error> {
         match unsafe { ptr.read() } {
-            -1 | 0 | 1 => Ok(ptr.cast()),
+            -1..=1 => Ok(ptr.cast()),
             _ => Err(()),
         }
     }
 }
+
+/// Error for when the bits of a [`NonZero`](core::num::NonZero) are zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ZeroError;
+
+impl fmt::Display for ZeroError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("bits were zero")
+    }
+}
+
+impl core::error::Error for ZeroError {}
+
+macro_rules! nonzero {
+    ($($prim:ident),*) => {
+        $(
+            unsafe impl CheckBits for core::num::NonZero<$prim> {
+                type Error = ZeroError;
+                type Bits = $prim;
+
+                #[inline(always)]
+                unsafe fn check_bits(ptr: NonNull<$prim>) -> Result<NonNull<Self>, ZeroError> {
+                    match unsafe { ptr.read() } {
+                        0 => Err(ZeroError),
+                        _ => Ok(ptr.cast()),
+                    }
+                }
+            }
+
+            // SAFETY: Zero is `None`, anything else is `Some`, so like the integer itself this is
+            //         valid for any bits, as reflected by `ZeroableInOption` and `NoUninitInOption`.
+            unsafe impl AnyBits for Option<core::num::NonZero<$prim>> {}
+            unsafe impl CheckBits for Option<core::num::NonZero<$prim>> {
+                type Error = Infallible;
+                type Bits = Self;
+
+                #[inline(always)]
+                unsafe fn check_bits(ptr: NonNull<Self>) -> Result<NonNull<Self>, Infallible> {
+                    Ok(ptr)
+                }
+            }
+        )*
+    };
+}
+
+nonzero!(u8, u16, u32, u64, u128, usize);
+nonzero!(i8, i16, i32, i64, i128, isize);
+
+// SAFETY: Raw pointers are valid for any address, so they're valid for any bits as long
+//         as their metadata is.
+//
+//         Bits that did not come from a pointer make a pointer without provenance, which is
+//         still a valid value, it just can not be used for accesses. Accessing through a raw
+//         pointer already requires `unsafe` code to prove the pointer is usable, so this does
+//         not allow anything safe code could not do with `ptr::without_provenance`.
+
+unsafe impl<T: Pointee<Metadata: AnyBits> + ?Sized> AnyBits for *const T {}
+unsafe impl<T: Pointee<Metadata: AnyBits> + ?Sized> CheckBits for *const T {
+    type Error = Infallible;
+    type Bits = Self;
+
+    #[inline(always)]
+    unsafe fn check_bits(ptr: NonNull<Self>) -> Result<NonNull<Self>, Infallible> {
+        Ok(ptr)
+    }
+}
+
+unsafe impl<T: Pointee<Metadata: AnyBits> + ?Sized> AnyBits for *mut T {}
+unsafe impl<T: Pointee<Metadata: AnyBits> + ?Sized> CheckBits for *mut T {
+    type Error = Infallible;
+    type Bits = Self;
+
+    #[inline(always)]
+    unsafe fn check_bits(ptr: NonNull<Self>) -> Result<NonNull<Self>, Infallible> {
+        Ok(ptr)
+    }
+}
+
+// SAFETY: A null pointer is `None`, anything else is `Some`.
+//
+//         This only holds for thin pointers, as a `None` fat pointer says nothing about its metadata.
+//
+//         `Option<&T>` is deliberately absent, as no bit pattern can prove a pointer is dereferenceable.
+
+unsafe impl<T> CheckBits for Option<NonNull<T>> {
+    type Error = Infallible;
+    type Bits = *mut T;
+
+    #[inline(always)]
+    unsafe fn check_bits(ptr: NonNull<*mut T>) -> Result<NonNull<Self>, Infallible> {
+        Ok(ptr.cast())
+    }
+}
+
+macro_rules! transparent {
+    (
+        $(
+            $($wrapper:ident)::+
+            $( : ( $( $rest:tt )* ) )?
+        ),* $(,)?
+    ) => {
+        $(
+            unsafe impl<T: CheckBits $( $($rest)* )?> CheckBits for $($wrapper)::+<T>
+            where
+                $($wrapper)::+<T>: Pointee<Metadata = T::Metadata>,
+            {
+                type Error = T::Error;
+                type Bits = T::Bits;
+
+                #[inline(always)]
+                unsafe fn check_bits(ptr: NonNull<T::Bits>) -> Result<NonNull<Self>, T::Error> {
+                    match unsafe { T::check_bits(ptr) } {
+                        Ok(ptr) => Ok(unsafe { NonNull::new_unchecked(ptr.as_ptr() as *mut Self) }),
+                        Err(err) => Err(err),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+// SAFETY: These are all `#[repr(transparent)]`, or guaranteed to have the same layout as `T`.
+
+transparent!(
+    core::num::Wrapping,
+    core::num::Saturating,
+    core::cmp::Reverse,
+    core::mem::ManuallyDrop: (+ ?Sized),
+    core::cell::Cell: (+ ?Sized),
+    core::cell::UnsafeCell: (+ ?Sized),
+);
+
+unsafe impl<T: ?Sized> AnyBits for core::marker::PhantomData<T> {}
+unsafe impl<T: ?Sized> CheckBits for core::marker::PhantomData<T> {
+    type Error = Infallible;
+    type Bits = Self;
+
+    #[inline(always)]
+    unsafe fn check_bits(ptr: NonNull<Self>) -> Result<NonNull<Self>, Infallible> {
+        Ok(ptr)
+    }
+}
+
+macro_rules! tuple_error {
+    ($($name:ident $variant:ident $idx:tt),* $(,)?) => {
+        /// Error for when an element of a tuple is invalid, with one variant per position.
+        ///
+        /// Positions beyond the length of the tuple default to [`Infallible`].
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum TupleError<$($name = Infallible),*> {
+            $(
+                #[doc = concat!("The element at index ", stringify!($idx), " was invalid.")]
+                $variant($name),
+            )*
+        }
+
+        impl<$($name: fmt::Display),*> fmt::Display for TupleError<$($name),*> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(
+                        Self::$variant(err) => write!(f, "element {} was invalid: {err}", $idx),
+                    )*
+                }
+            }
+        }
+
+        impl<$($name: fmt::Debug + fmt::Display),*> core::error::Error for TupleError<$($name),*> {}
+
+        impl<$($name: Uninhabited),*> Uninhabited for TupleError<$($name),*> {
+            #[inline]
+            fn unreachable<T>(self) -> T {
+                match self {
+                    $(
+                        Self::$variant(err) => err.unreachable(),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+tuple_error!(
+    E0 Elem0 0,
+    E1 Elem1 1,
+    E2 Elem2 2,
+    E3 Elem3 3,
+    E4 Elem4 4,
+    E5 Elem5 5,
+    E6 Elem6 6,
+    E7 Elem7 7,
+    E8 Elem8 8,
+    E9 Elem9 9,
+    E10 Elem10 10,
+    E11 Elem11 11,
+    E12 Elem12 12,
+    E13 Elem13 13,
+    E14 Elem14 14,
+    E15 Elem15 15,
+);
+
+// SAFETY: Tuples have no guaranteed layout, so it is asserted that every element
+//         has the same offset within `Self` as it does within `Self::Bits`.
+
+macro_rules! check_bits_tuple {
+    (
+        $(
+            (
+                $( $name:ident $idx:tt $variant:ident ),*
+            )
+        ),* $(,)?
+    ) => {
+        $(
+            unsafe impl<$($name: AnyBits),*> AnyBits for ($($name,)*) {}
+            unsafe impl<$($name: CheckBits<Bits: Sized>),*> CheckBits for ($($name,)*) {
+                type Error = TupleError<$($name::Error),*>;
+                type Bits = ($($name::Bits,)*);
+
+                #[inline(always)]
+                unsafe fn check_bits(ptr: NonNull<Self::Bits>) -> Result<NonNull<Self>, Self::Error> {
+                    const {
+                        assert!(
+                            size_of::<Self>() == size_of::<Self::Bits>(),
+                            "size mismatch"
+                        );
+
+                        $(
+                            assert!(
+                                offset_of!(Self, $idx) == offset_of!(Self::Bits, $idx),
+                                "field offset mismatch"
+                            );
+                        )*
+                    };
+
+                    let bits = ptr.as_ptr();
+
+                    $(
+                        if let Err(err) = unsafe { $name::check_bits(NonNull::new_unchecked(&raw mut (*bits).$idx)) } {
+                            return Err(TupleError::$variant(err));
+                        }
+                    )*
+
+                    Ok(ptr.cast())
+                }
+            }
+        )*
+    };
+}
+
+check_bits_tuple!(
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6, T7 7 Elem7, T8 8 Elem8, T9 9 Elem9, T10 10 Elem10, T11 11 Elem11, T12 12 Elem12, T13 13 Elem13, T14 14 Elem14, T15 15 Elem15),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6, T7 7 Elem7, T8 8 Elem8, T9 9 Elem9, T10 10 Elem10, T11 11 Elem11, T12 12 Elem12, T13 13 Elem13, T14 14 Elem14),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6, T7 7 Elem7, T8 8 Elem8, T9 9 Elem9, T10 10 Elem10, T11 11 Elem11, T12 12 Elem12, T13 13 Elem13),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6, T7 7 Elem7, T8 8 Elem8, T9 9 Elem9, T10 10 Elem10, T11 11 Elem11, T12 12 Elem12),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6, T7 7 Elem7, T8 8 Elem8, T9 9 Elem9, T10 10 Elem10, T11 11 Elem11),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6, T7 7 Elem7, T8 8 Elem8, T9 9 Elem9, T10 10 Elem10),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6, T7 7 Elem7, T8 8 Elem8, T9 9 Elem9),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6, T7 7 Elem7, T8 8 Elem8),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6, T7 7 Elem7),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5, T6 6 Elem6),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4, T5 5 Elem5),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3, T4 4 Elem4),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2, T3 3 Elem3),
+    (T0 0 Elem0, T1 1 Elem1, T2 2 Elem2),
+    (T0 0 Elem0, T1 1 Elem1),
+    (T0 0 Elem0),
+);
@@ -0,0 +1,227 @@
+// The errors mirror the runtime `CheckBits` implementations.
+#![allow(clippy::result_unit_err)]
+
+use core::{cmp::Ordering, convert::Infallible, str::Utf8Error};
+
+use crate::{CheckBits, ZeroError};
+
+/// Check whether `bits` is a valid [`bool`] in a `const` context.
+///
+/// This gives the same answer as [`bool`]'s [`CheckBits`] implementation.
+#[inline]
+pub const fn const_check_bool(bits: u8) -> Result<bool, ()> {
+    match bits {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(()),
+    }
+}
+
+/// Check whether `bits` is a valid [`char`] in a `const` context.
+///
+/// This gives the same answer as [`char`]'s [`CheckBits`] implementation.
+#[inline]
+pub const fn const_check_char(bits: u32) -> Result<char, ()> {
+    match char::from_u32(bits) {
+        Some(c) => Ok(c),
+        None => Err(()),
+    }
+}
+
+/// Check whether `bits` is a valid [`Ordering`] in a `const` context.
+///
+/// This gives the same answer as [`Ordering`]'s [`CheckBits`] implementation.
+#[inline]
+pub const fn const_check_ordering(bits: i8) -> Result<Ordering, ()> {
+    match bits {
+        -1 => Ok(Ordering::Less),
+        0 => Ok(Ordering::Equal),
+        1 => Ok(Ordering::Greater),
+        _ => Err(()),
+    }
+}
+
+/// Check whether `bits` is a valid [`str`] in a `const` context.
+///
+/// This gives the same answer as [`str`]'s [`CheckBits`] implementation.
+#[inline]
+pub const fn const_check_str(bits: &[u8]) -> Result<&str, Utf8Error> {
+    core::str::from_utf8(bits)
+}
+
+/// Cast `bits` to a `T` in a `const` context.
+///
+/// This gives the same answer as [`CheckBits::cast_ref`].
+#[inline]
+pub const fn const_cast<T: ConstCheckBits>(bits: &T::Bits) -> Result<&T, T::Error> {
+    let ptr = core::ptr::from_ref(bits).cast::<u8>();
+
+    // SAFETY: `ptr` is valid for reads of `size_of::<T::Bits>()` bytes, and is aligned for `T::Bits`.
+    match unsafe { check_raw::<T>(ptr, size_of::<T::Bits>()) } {
+        // SAFETY: We just validated `bits`, and `T` has the same layout as `T::Bits`.
+        Ok(()) => Ok(unsafe { &*ptr.cast::<T>() }),
+        Err(err) => Err(err),
+    }
+}
+
+/// Cast an array of `bits` to an array of `T` in a `const` context.
+///
+/// This gives the same answer as [`CheckBits::cast_ref`].
+#[inline]
+pub const fn const_cast_array<T: ConstCheckBits, const N: usize>(
+    bits: &[T::Bits; N],
+) -> Result<&[T; N], T::Error> {
+    const_cast::<[T; N]>(bits)
+}
+
+/// Trait for [`CheckBits`] types that can be validated in a `const` context.
+///
+/// This is implemented for the primitives supported by [`CheckBits`], and arrays of them.
+///
+/// # Safety
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub unsafe trait ConstCheckBits:
+    CheckBits<Bits: Sized, Error: Copy> + Sized + sealed::Sealed
+{
+    #[doc(hidden)]
+    const KIND: ConstCheckKind;
+
+    /// The error to return for invalid bits, [`None`] if there are none.
+    #[doc(hidden)]
+    const ERROR: Option<Self::Error>;
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstCheckKind {
+    Any,
+    Bool,
+    Char,
+    Ordering,
+    NonZero(usize),
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Check whether the `size` bytes starting at `ptr` are valid for `T`.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `size` bytes, and be aligned for `T::Bits`.
+#[inline]
+const unsafe fn check_raw<T: ConstCheckBits>(ptr: *const u8, size: usize) -> Result<(), T::Error> {
+    let valid = match T::KIND {
+        ConstCheckKind::Any => true,
+        ConstCheckKind::Bool => {
+            let mut i = 0;
+
+            // SAFETY: The caller ensures `ptr` is valid for `size` bytes.
+            while i < size && const_check_bool(unsafe { ptr.add(i).read() }).is_ok() {
+                i += 1;
+            }
+
+            i == size
+        }
+        ConstCheckKind::Char => {
+            let ptr = ptr.cast::<u32>();
+            let len = size / size_of::<u32>();
+            let mut i = 0;
+
+            // SAFETY: The caller ensures `ptr` is valid for `size` bytes, and is aligned
+            //         for `T::Bits`, which is made up of `u32`s.
+            while i < len && const_check_char(unsafe { ptr.add(i).read() }).is_ok() {
+                i += 1;
+            }
+
+            i == len
+        }
+        ConstCheckKind::Ordering => {
+            let ptr = ptr.cast::<i8>();
+            let mut i = 0;
+
+            // SAFETY: The caller ensures `ptr` is valid for `size` bytes.
+            while i < size && const_check_ordering(unsafe { ptr.add(i).read() }).is_ok() {
+                i += 1;
+            }
+
+            i == size
+        }
+        ConstCheckKind::NonZero(width) => {
+            let mut i = 0;
+
+            while i < size {
+                let mut zero = true;
+                let mut j = 0;
+
+                // SAFETY: The caller ensures `ptr` is valid for `size` bytes, which is a
+                //         multiple of `width`.
+                while j < width {
+                    zero &= unsafe { ptr.add(i + j).read() } == 0;
+                    j += 1;
+                }
+
+                if zero {
+                    break;
+                }
+
+                i += width;
+            }
+
+            i == size
+        }
+    };
+
+    if valid {
+        return Ok(());
+    }
+
+    match T::ERROR {
+        Some(err) => Err(err),
+        None => unreachable!(),
+    }
+}
+
+macro_rules! const_check {
+    ($kind:expr, $error:expr => $($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            unsafe impl ConstCheckBits for $ty {
+                const KIND: ConstCheckKind = $kind;
+                const ERROR: Option<Self::Error> = $error;
+            }
+        )*
+    };
+}
+
+const_check!(ConstCheckKind::Any, None::<Infallible> => ());
+const_check!(ConstCheckKind::Any, None::<Infallible> => u8, u16, u32, u64, u128, usize);
+const_check!(ConstCheckKind::Any, None::<Infallible> => i8, i16, i32, i64, i128, isize);
+const_check!(ConstCheckKind::Any, None::<Infallible> => f32, f64);
+const_check!(ConstCheckKind::Bool, Some(()) => bool);
+const_check!(ConstCheckKind::Char, Some(()) => char);
+const_check!(ConstCheckKind::Ordering, Some(()) => Ordering);
+
+macro_rules! nonzero {
+    ($($prim:ident),*) => {
+        $(
+            const_check!(
+                ConstCheckKind::NonZero(size_of::<$prim>()),
+                Some(ZeroError) => core::num::NonZero<$prim>
+            );
+        )*
+    };
+}
+
+nonzero!(u8, u16, u32, u64, u128, usize);
+nonzero!(i8, i16, i32, i64, i128, isize);
+
+impl<T: ConstCheckBits, const N: usize> sealed::Sealed for [T; N] {}
+
+unsafe impl<T: ConstCheckBits, const N: usize> ConstCheckBits for [T; N] {
+    const KIND: ConstCheckKind = T::KIND;
+    const ERROR: Option<T::Error> = T::ERROR;
+}
@@ -1,6 +1,13 @@
-use crate::Pointee;
+use crate::{
+    macros::{impl_for_fn_ptr, impl_for_simd},
+    Pointee,
+};
 
 /// Trait for types that contain no interior mutability, ignoring through indirection.
+///
+/// # Safety
+///
+/// `Self` must not contain an [`UnsafeCell`](core::cell::UnsafeCell) outside of indirection.
 pub unsafe trait Freeze {}
 
 unsafe impl<T: Freeze> Freeze for [T] {}
@@ -29,6 +36,31 @@ unsafe impl<T: ?Sized> Freeze for core::marker::PhantomData<T> {}
 unsafe impl<T: Freeze> Freeze for Option<T> {}
 unsafe impl<T: Freeze, E: Freeze> Freeze for Result<T, E> {}
 
+// SAFETY: `Pin` is `#[repr(transparent)]`.
+unsafe impl<P: Freeze> Freeze for core::pin::Pin<P> {}
+
+// SAFETY: The ranges only ever contain their bounds, and a `bool` for `RangeInclusive`.
+
+unsafe impl<T: Freeze> Freeze for core::ops::Range<T> {}
+unsafe impl<T: Freeze> Freeze for core::ops::RangeFrom<T> {}
+unsafe impl<T: Freeze> Freeze for core::ops::RangeTo<T> {}
+unsafe impl<T: Freeze> Freeze for core::ops::RangeInclusive<T> {}
+unsafe impl<T: Freeze> Freeze for core::ops::RangeToInclusive<T> {}
+unsafe impl Freeze for core::ops::RangeFull {}
+unsafe impl<T: Freeze> Freeze for core::ops::Bound<T> {}
+
+// SAFETY: The heap allocation is indirection.
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: ?Sized> Freeze for alloc::boxed::Box<T> {}
+#[cfg(feature = "alloc")]
+unsafe impl<T> Freeze for alloc::vec::Vec<T> {}
+#[cfg(feature = "alloc")]
+unsafe impl Freeze for alloc::string::String {}
+
+// SAFETY: Function pointers point to code, which is immutable.
+impl_for_fn_ptr!(unsafe impl Freeze);
+
 macro_rules! freeze {
     ($($ty:ty),*) => {
         $(unsafe impl Freeze for $ty {})*
@@ -51,6 +83,13 @@ freeze!(str);
 freeze!(core::cmp::Ordering);
 freeze!(core::marker::PhantomPinned);
 
+// SAFETY: None of these contain an `UnsafeCell`.
+freeze!(core::time::Duration);
+freeze!(core::convert::Infallible);
+freeze!(core::ffi::c_void);
+
+impl_for_simd!(freeze);
+
 macro_rules! freeze_tuple {
     (
         $(
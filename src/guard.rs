@@ -0,0 +1,177 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+};
+
+use crate::{fill_zeroes, ptr as legume_ptr, CheckBits, Zeroable};
+
+/// Policy for what a [`BitsMutGuard`] does when the bits it guards fail validation.
+///
+/// # Safety
+///
+/// [`BitsMutPolicy::on_invalid`] must leave `bits` as a valid `T` before it returns or unwinds.
+pub unsafe trait BitsMutPolicy<T: CheckBits + ?Sized> {
+    /// Whatever state the policy needs to recover from invalid bits.
+    type Saved;
+
+    /// Save whatever state is needed before the bits are exposed.
+    fn save(bits: &T::Bits) -> Self::Saved;
+
+    /// Handle `bits` failing validation with `err`.
+    fn on_invalid(bits: &mut T::Bits, saved: Self::Saved, err: T::Error);
+}
+
+/// A [`BitsMutPolicy`] that zeroes the bits and then panics when they are invalid.
+///
+/// Zeroing keeps the value valid if the panic is caught. As with any panic in a destructor,
+/// this aborts if the bits are found invalid while already unwinding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PanicOnInvalid;
+
+unsafe impl<T: CheckBits<Error: fmt::Debug> + Zeroable + ?Sized> BitsMutPolicy<T>
+    for PanicOnInvalid
+{
+    type Saved = ();
+
+    #[inline]
+    fn save(_: &T::Bits) {}
+
+    #[cold]
+    #[track_caller]
+    fn on_invalid(bits: &mut T::Bits, _: (), err: T::Error) {
+        // All zeroes is a valid `T`, so the value is valid again before unwinding.
+        let _ = fill_zeroes(bits);
+
+        panic!("bits failed validation: {err:?}");
+    }
+}
+
+/// A [`BitsMutPolicy`] that restores the bits saved when the guard was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct RestoreOnInvalid;
+
+unsafe impl<T: CheckBits<Bits: Copy> + ?Sized> BitsMutPolicy<T> for RestoreOnInvalid {
+    type Saved = T::Bits;
+
+    #[inline]
+    fn save(bits: &T::Bits) -> T::Bits {
+        *bits
+    }
+
+    #[cold]
+    fn on_invalid(bits: &mut T::Bits, saved: T::Bits, _: T::Error) {
+        *bits = saved;
+    }
+}
+
+/// Guard that exposes the bits of some `T` mutably, validating them when dropped.
+///
+/// What happens on invalid bits is decided by `P`, see [`PanicOnInvalid`] and [`RestoreOnInvalid`].
+///
+/// As the bits are only validated when the guard is dropped, creating one is unsafe. Prefer
+/// [`with_bits_mut`], [`with_bits_mut_or_restore`], or [`BitsMutGuard::with`].
+pub struct BitsMutGuard<'a, T: CheckBits + ?Sized, P: BitsMutPolicy<T> = PanicOnInvalid> {
+    bits: NonNull<T::Bits>,
+    saved: ManuallyDrop<P::Saved>,
+    _marker: PhantomData<(&'a mut T, P)>,
+}
+
+impl<'a, T: CheckBits + ?Sized, P: BitsMutPolicy<T>> BitsMutGuard<'a, T, P> {
+    /// Expose the bits of `value` to `f`, validating them after `f` returns or unwinds.
+    #[inline]
+    pub fn with<R>(value: &'a mut T, f: impl FnOnce(&mut T::Bits) -> R) -> R {
+        // SAFETY: The guard is dropped when this returns, or while unwinding out of `f`.
+        let mut guard = unsafe { BitsMutGuard::<'a, T, P>::new(value) };
+
+        f(&mut guard)
+    }
+
+    /// Expose the bits of `value`.
+    ///
+    /// # Safety
+    ///
+    /// The guard must be dropped before `value` is used again, it must not be leaked with
+    /// [`core::mem::forget`] or similar, as that would skip validation and leave `value` invalid.
+    #[inline]
+    #[must_use]
+    pub unsafe fn new(value: &'a mut T) -> BitsMutGuard<'a, T, P> {
+        let ptr = ptr::from_mut(value);
+        let ptr = legume_ptr::from_raw_parts_mut::<T::Bits>(ptr.cast(), legume_ptr::metadata(ptr));
+
+        // SAFETY: `ptr` came from a reference, and `T::Bits` has the same layout as `T`.
+        let bits = unsafe { NonNull::new_unchecked(ptr) };
+
+        BitsMutGuard {
+            // SAFETY: Every valid `T` is a valid `T::Bits`.
+            saved: ManuallyDrop::new(P::save(unsafe { bits.as_ref() })),
+            bits,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Expose the bits of `value` to `f`, zeroing them and panicking if they are invalid afterwards.
+///
+/// See [`PanicOnInvalid`].
+#[inline]
+pub fn with_bits_mut<T: CheckBits<Error: fmt::Debug> + Zeroable + ?Sized, R>(
+    value: &mut T,
+    f: impl FnOnce(&mut T::Bits) -> R,
+) -> R {
+    BitsMutGuard::<T, PanicOnInvalid>::with(value, f)
+}
+
+/// Expose the bits of `value` to `f`, restoring them if they are invalid afterwards.
+///
+/// See [`RestoreOnInvalid`].
+#[inline]
+pub fn with_bits_mut_or_restore<T: CheckBits<Bits: Copy> + ?Sized, R>(
+    value: &mut T,
+    f: impl FnOnce(&mut T::Bits) -> R,
+) -> R {
+    BitsMutGuard::<T, RestoreOnInvalid>::with(value, f)
+}
+
+impl<T: CheckBits + ?Sized, P: BitsMutPolicy<T>> Deref for BitsMutGuard<'_, T, P> {
+    type Target = T::Bits;
+
+    #[inline]
+    fn deref(&self) -> &T::Bits {
+        // SAFETY: We have unique access to the bits for the lifetime of the guard.
+        unsafe { self.bits.as_ref() }
+    }
+}
+
+impl<T: CheckBits + ?Sized, P: BitsMutPolicy<T>> DerefMut for BitsMutGuard<'_, T, P> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T::Bits {
+        // SAFETY: We have unique access to the bits for the lifetime of the guard.
+        unsafe { self.bits.as_mut() }
+    }
+}
+
+impl<T: CheckBits + ?Sized, P: BitsMutPolicy<T>> Drop for BitsMutGuard<'_, T, P> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: This is only ever taken here.
+        let saved = unsafe { ManuallyDrop::take(&mut self.saved) };
+
+        // SAFETY: We have unique access to the bits, so they're valid for reads.
+        if let Err(err) = unsafe { T::check_bits(self.bits) } {
+            // SAFETY: See above.
+            P::on_invalid(unsafe { self.bits.as_mut() }, saved, err);
+        }
+    }
+}
+
+impl<T: CheckBits<Bits: fmt::Debug> + ?Sized, P: BitsMutPolicy<T>> fmt::Debug
+    for BitsMutGuard<'_, T, P>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
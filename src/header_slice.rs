@@ -0,0 +1,319 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+    alloc::{alloc, handle_alloc_error},
+    boxed::Box,
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
+use core::ptr;
+use core::{
+    alloc::{Layout, LayoutError},
+    fmt,
+    ptr::NonNull,
+};
+
+use crate::{
+    ptr::{from_raw_parts_nonnull, DstLayout, KnownAlign, MetadataPosition, Pointee, Unsize},
+    CheckBits, Freeze, NoUninit, Unaligned,
+};
+
+/// A header followed by a slice of elements, as in `HeaderSlice<H, [T]>`.
+#[repr(C)]
+pub struct HeaderSlice<H, S: ?Sized> {
+    /// The header.
+    pub header: H,
+    /// The elements following the header.
+    pub slice: S,
+}
+
+// SAFETY: The slice is the last field of a `#[repr(C)]` struct, so a pointer to a
+//         `HeaderSlice` carries the same metadata as a pointer to the slice.
+unsafe impl<H, T> Pointee for HeaderSlice<H, [T]> {
+    type Metadata = usize;
+
+    const METADATA_POSITION: MetadataPosition = <[T] as Pointee>::METADATA_POSITION;
+}
+
+unsafe impl<H, T> DstLayout for HeaderSlice<H, [T]> {
+    #[inline]
+    fn layout_for(len: usize) -> Result<Layout, LayoutError> {
+        match Self::layout(len) {
+            Ok((layout, _)) => Ok(layout),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+unsafe impl<H, T> KnownAlign for HeaderSlice<H, [T]> {
+    const ALIGN: usize = if align_of::<H>() > align_of::<T>() {
+        align_of::<H>()
+    } else {
+        align_of::<T>()
+    };
+}
+
+unsafe impl<H, T, const N: usize> Unsize<HeaderSlice<H, [T]>> for HeaderSlice<H, [T; N]> {
+    #[inline]
+    fn unsize_meta() -> usize {
+        N
+    }
+}
+
+// SAFETY: Both `H` and `T` have an alignment of `1`, so there is no padding.
+unsafe impl<H: NoUninit + Unaligned, T: NoUninit + Unaligned> NoUninit for HeaderSlice<H, [T]> {}
+
+unsafe impl<H: Freeze, T: Freeze> Freeze for HeaderSlice<H, [T]> {}
+
+impl<H, T> HeaderSlice<H, [T]> {
+    /// Get the layout of a [`HeaderSlice`] with `len` elements, and the offset of the elements.
+    #[inline]
+    fn layout(len: usize) -> Result<(Layout, usize), LayoutError> {
+        let (layout, offset) = Layout::new::<H>().extend(Layout::array::<T>(len)?)?;
+
+        Ok((layout.pad_to_align(), offset))
+    }
+
+    /// Move a header and elements into a new boxed [`HeaderSlice`].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn new_box(header: H, mut items: Vec<T>) -> Box<Self> {
+        let len = items.len();
+
+        let (layout, offset) = match Self::layout(len) {
+            Ok(layout) => layout,
+            Err(_) => panic!("`HeaderSlice` layout overflowed"),
+        };
+
+        let base = if layout.size() == 0 {
+            // SAFETY: Alignments are never zero.
+            unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(layout.align())) }
+        } else {
+            // SAFETY: The layout is not zero-sized.
+            match NonNull::new(unsafe { alloc(layout) }) {
+                Some(base) => base,
+                None => handle_alloc_error(layout),
+            }
+        };
+
+        // SAFETY: The allocation has room for the header and `len` elements at `offset`, and
+        //         we take ownership of the elements by setting the length of `items` to zero.
+        unsafe {
+            base.cast::<H>().write(header);
+
+            ptr::copy_nonoverlapping(items.as_ptr(), base.add(offset).cast::<T>().as_ptr(), len);
+            items.set_len(0);
+
+            Box::from_raw(from_raw_parts_nonnull::<Self>(base.cast(), len).as_ptr())
+        }
+    }
+
+    /// Clone a header and elements into a new boxed [`HeaderSlice`].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn from_slice_box(header: H, items: &[T]) -> Box<Self>
+    where
+        T: Clone,
+    {
+        Self::new_box(header, items.to_vec())
+    }
+
+    /// Move a header and elements into a new reference counted [`HeaderSlice`].
+    ///
+    /// This allocates a box first and then moves it into the [`Arc`](alloc::sync::Arc), as
+    /// there is no stable way to allocate an `Arc` for a custom dynamically sized type.
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[must_use]
+    pub fn new_arc(header: H, items: Vec<T>) -> alloc::sync::Arc<Self> {
+        Self::new_box(header, items).into()
+    }
+
+    /// Clone a header and elements into a new reference counted [`HeaderSlice`].
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[must_use]
+    pub fn from_slice_arc(header: H, items: &[T]) -> alloc::sync::Arc<Self>
+    where
+        T: Clone,
+    {
+        Self::new_arc(header, items.to_vec())
+    }
+
+    /// Interpret bytes as a [`HeaderSlice`], validating the header and elements.
+    ///
+    /// The number of elements is computed from the bytes remaining after the header.
+    #[inline]
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<&Self, HeaderSliceError<H::Error, T::Error>>
+    where
+        H: CheckBits<Bits: Sized> + Freeze,
+        T: CheckBits<Bits: Sized> + Freeze,
+    {
+        if !bytes.as_ptr().addr().is_multiple_of(Self::ALIGN) {
+            return Err(HeaderSliceError::Alignment);
+        }
+
+        let offset = match Self::layout(0) {
+            Ok((_, offset)) => offset,
+            Err(_) => return Err(HeaderSliceError::Size),
+        };
+
+        let len = match (bytes.len().checked_sub(offset), size_of::<T>()) {
+            (Some(0), _) => 0,
+            (Some(rest), size) if size != 0 => rest / size,
+            _ => return Err(HeaderSliceError::Size),
+        };
+
+        match Self::layout(len) {
+            Ok((layout, _)) if layout.size() == bytes.len() => {}
+            _ => return Err(HeaderSliceError::Size),
+        }
+
+        let base = NonNull::from(bytes).cast::<u8>();
+
+        // SAFETY: The bytes are aligned and large enough for the header, and `H::Bits` is valid
+        //         for any initialized bits.
+        if let Err(err) = unsafe { H::check_bits(base.cast()) } {
+            return Err(HeaderSliceError::Header(err));
+        }
+
+        // SAFETY: The bytes are aligned and large enough for `len` elements at `offset`.
+        let items = from_raw_parts_nonnull::<[T::Bits]>(unsafe { base.add(offset).cast() }, len);
+
+        // SAFETY: `T::Bits` is valid for any initialized bits.
+        if let Err(err) = unsafe { <[T]>::check_bits(items) } {
+            return Err(HeaderSliceError::Slice(err));
+        }
+
+        // SAFETY: We checked the size, alignment, and validity of the bytes, and neither `H`
+        //         nor `T` contain interior mutability.
+        Ok(unsafe { from_raw_parts_nonnull::<Self>(base.cast(), len).as_ref() })
+    }
+}
+
+/// Error for when bytes are not a valid [`HeaderSlice`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HeaderSliceError<H, T> {
+    /// The bytes were not aligned for the [`HeaderSlice`].
+    Alignment,
+    /// The bytes were not the size of a [`HeaderSlice`] with a whole number of elements.
+    Size,
+    /// The header was invalid.
+    Header(H),
+    /// One of the elements was invalid.
+    Slice(T),
+}
+
+impl<H: fmt::Display, T: fmt::Display> fmt::Display for HeaderSliceError<H, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Alignment => f.write_str("bytes were misaligned"),
+            Self::Size => f.write_str("bytes were not a whole number of elements"),
+            Self::Header(err) => write!(f, "header was invalid: {err}"),
+            Self::Slice(err) => write!(f, "element was invalid: {err}"),
+        }
+    }
+}
+
+impl<H: fmt::Debug + fmt::Display, T: fmt::Debug + fmt::Display> core::error::Error
+    for HeaderSliceError<H, T>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec, vec::Vec};
+
+    use super::{HeaderSlice, HeaderSliceError};
+
+    /// Bytes aligned for any of the headers used here.
+    #[repr(C, align(8))]
+    struct Aligned([u8; 16]);
+
+    #[test]
+    fn new_box() {
+        let value = HeaderSlice::new_box(7_u64, vec![1_u8, 2, 3]);
+
+        assert_eq!(value.header, 7);
+        assert_eq!(&value.slice, &[1, 2, 3]);
+        assert_eq!(size_of_val(&*value), 16);
+    }
+
+    #[test]
+    fn new_box_empty_and_zero_sized() {
+        let value = HeaderSlice::new_box(7_u64, Vec::<u8>::new());
+        assert!(value.slice.is_empty());
+
+        let value = HeaderSlice::new_box((), vec![(); 3]);
+        assert_eq!(value.slice.len(), 3);
+    }
+
+    #[test]
+    fn new_box_drops_once() {
+        let counter = Rc::new(());
+        let value = HeaderSlice::new_box(counter.clone(), vec![counter.clone(), counter.clone()]);
+
+        assert_eq!(Rc::strong_count(&counter), 4);
+        drop(value);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn new_arc() {
+        let value = HeaderSlice::from_slice_arc(7_u64, &[1_u8, 2, 3]);
+
+        assert_eq!(value.header, 7);
+        assert_eq!(&value.slice, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        let bytes = Aligned([7, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let value = HeaderSlice::<u64, [u8]>::try_from_bytes(&bytes.0).unwrap();
+        assert_eq!(value.header, u64::from_le_bytes([7, 0, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(&value.slice, &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let value = HeaderSlice::<u64, [u8]>::try_from_bytes(&bytes.0[..8]).unwrap();
+        assert!(value.slice.is_empty());
+    }
+
+    #[test]
+    fn try_from_bytes_alignment() {
+        let bytes = Aligned([0; 16]);
+
+        assert_eq!(
+            HeaderSlice::<u64, [u8]>::try_from_bytes(&bytes.0[1..]).map(|_| ()),
+            Err(HeaderSliceError::Alignment),
+        );
+    }
+
+    #[test]
+    fn try_from_bytes_size() {
+        let bytes = Aligned([0; 16]);
+
+        assert_eq!(
+            HeaderSlice::<u64, [u8]>::try_from_bytes(&bytes.0[..4]).map(|_| ()),
+            Err(HeaderSliceError::Size),
+        );
+        assert_eq!(
+            HeaderSlice::<u64, [u8]>::try_from_bytes(&bytes.0[..12]).map(|_| ()),
+            Err(HeaderSliceError::Size),
+        );
+        assert_eq!(
+            HeaderSlice::<u32, [u16]>::try_from_bytes(&bytes.0[..7]).map(|_| ()),
+            Err(HeaderSliceError::Size),
+        );
+    }
+
+    #[test]
+    fn try_from_bytes_invalid() {
+        let bytes = Aligned([1, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        assert!(matches!(
+            HeaderSlice::<bool, [bool]>::try_from_bytes(&bytes.0[..4]),
+            Err(HeaderSliceError::Slice(_)),
+        ));
+        assert!(matches!(
+            HeaderSlice::<bool, [u8]>::try_from_bytes(&bytes.0[3..4]),
+            Err(HeaderSliceError::Header(_)),
+        ));
+    }
+}
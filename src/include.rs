@@ -0,0 +1,94 @@
+use crate::{AnyBits, ConstCheckBits, Freeze};
+
+/// Include a file as a correctly aligned `&'static T`, where `T` is [`AnyBits`].
+///
+/// The size of the file must match the size of `T`, otherwise the build fails.
+#[macro_export]
+macro_rules! include_aligned {
+    ($ty:ty, $path:expr $(,)?) => {{
+        const LEN: usize = ::core::include_bytes!($path).len();
+
+        #[repr(C)]
+        struct Aligned {
+            _align: [$ty; 0],
+            bytes: [u8; LEN],
+        }
+
+        const ALIGNED: &Aligned = &Aligned {
+            _align: [],
+            bytes: *::core::include_bytes!($path),
+        };
+
+        const VALUE: &$ty = $crate::__include_aligned::<$ty>(&ALIGNED.bytes);
+
+        VALUE
+    }};
+}
+
+/// Include a file as a correctly aligned `&'static T`, where `T` is [`ConstCheckBits`].
+///
+/// The contents of the file are validated at compile time, the build fails if they are invalid
+/// or if the size of the file does not match the size of `T`.
+///
+/// This also accepts `str`, in which case the file must be valid UTF-8.
+#[macro_export]
+macro_rules! include_checked {
+    (str, $path:expr $(,)?) => {{
+        const VALUE: &str = match $crate::const_check_str(::core::include_bytes!($path)) {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(_) => ::core::panic!("included file is not valid UTF-8"),
+        };
+
+        VALUE
+    }};
+    ($ty:ty, $path:expr $(,)?) => {{
+        const LEN: usize = ::core::include_bytes!($path).len();
+
+        #[repr(C)]
+        struct Aligned {
+            _align: [$ty; 0],
+            bytes: [u8; LEN],
+        }
+
+        const ALIGNED: &Aligned = &Aligned {
+            _align: [],
+            bytes: *::core::include_bytes!($path),
+        };
+
+        const VALUE: &$ty = $crate::__include_checked::<$ty>(&ALIGNED.bytes);
+
+        VALUE
+    }};
+}
+
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub const fn __include_aligned<T: AnyBits + Freeze>(bytes: &[u8]) -> &T {
+    assert!(
+        bytes.len() == size_of::<T>(),
+        "included file does not match the size of the type"
+    );
+
+    // SAFETY: The macro aligns `bytes` for `T`, we just checked the size, and `T` is valid for any bits.
+    unsafe { &*bytes.as_ptr().cast::<T>() }
+}
+
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub const fn __include_checked<T: ConstCheckBits + Freeze>(bytes: &[u8]) -> &T {
+    assert!(
+        bytes.len() == size_of::<T::Bits>(),
+        "included file does not match the size of the type"
+    );
+
+    // SAFETY: The macro aligns `bytes` for `T`, which has the same alignment as `T::Bits`, we just
+    //         checked the size, and `T::Bits` is valid for any bits.
+    let bits = unsafe { &*bytes.as_ptr().cast::<T::Bits>() };
+
+    match crate::const_cast::<T>(bits) {
+        Ok(value) => value,
+        Err(_) => panic!("included file is not valid for the type"),
+    }
+}
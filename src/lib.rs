@@ -12,6 +12,19 @@ mod util;
 mod check_bits;
 pub use check_bits::*;
 
+mod const_check;
+pub use const_check::*;
+
+mod include;
+#[doc(hidden)]
+pub use include::*;
+
+mod validated;
+pub use validated::*;
+
+mod guard;
+pub use guard::*;
+
 // mod any_bits;
 // pub use any_bits::*;
 
@@ -36,11 +49,22 @@ pub use freeze::*;
 mod unaligned;
 pub use unaligned::*;
 
+mod header_slice;
+pub use header_slice::*;
+
+#[cfg(feature = "alloc")]
+mod thin_box;
+#[cfg(feature = "alloc")]
+pub use thin_box::*;
+
 /// Module for handling pointers.
 pub mod ptr;
 #[doc(inline)]
 pub use ptr::{Pointee, Thin};
 
+#[cfg(feature = "derive")]
+pub use legume_derive::Pointee;
+
 #[inline]
 #[must_use]
 pub const fn bytes_of<T: NoUninit + Freeze + ?Sized>(src: &T) -> &[u8] {
@@ -41,3 +41,117 @@ macro_rules! impl_for_atomic {
 }
 
 pub(crate) use impl_for_atomic;
+
+macro_rules! impl_for_fn_ptr {
+    (
+        unsafe impl $trait:path
+    ) => {
+        $crate::macros::impl_for_fn_ptr!(@abi $trait => "Rust", "C", "C-unwind", "system", "system-unwind");
+
+        #[cfg(target_arch = "x86")]
+        $crate::macros::impl_for_fn_ptr!(@abi $trait => "cdecl", "cdecl-unwind");
+
+        #[cfg(target_arch = "x86_64")]
+        $crate::macros::impl_for_fn_ptr!(@abi $trait => "sysv64", "sysv64-unwind");
+
+        #[cfg(target_arch = "arm")]
+        $crate::macros::impl_for_fn_ptr!(@abi $trait => "aapcs", "aapcs-unwind");
+    };
+
+    (@abi $trait:path => $($abi:tt),*) => {
+        $(
+            $crate::macros::impl_for_fn_ptr!(@args $trait, $abi => (
+                (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15),
+                (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14),
+                (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13),
+                (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12),
+                (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11),
+                (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10),
+                (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9),
+                (T0, T1, T2, T3, T4, T5, T6, T7, T8),
+                (T0, T1, T2, T3, T4, T5, T6, T7),
+                (T0, T1, T2, T3, T4, T5, T6),
+                (T0, T1, T2, T3, T4, T5),
+                (T0, T1, T2, T3, T4),
+                (T0, T1, T2, T3),
+                (T0, T1, T2),
+                (T0, T1),
+                (T0),
+                (),
+            ));
+        )*
+    };
+
+    (@args $trait:path, $abi:tt => ($(($($arg:ident),*)),* $(,)?)) => {
+        $(
+            unsafe impl<Ret $(, $arg)*> $trait for extern $abi fn($($arg),*) -> Ret {}
+            unsafe impl<Ret $(, $arg)*> $trait for unsafe extern $abi fn($($arg),*) -> Ret {}
+        )*
+    };
+}
+
+pub(crate) use impl_for_fn_ptr;
+
+macro_rules! impl_for_simd {
+    (
+        $callback:ident
+    ) => {
+        #[cfg(target_arch = "x86")]
+        $callback!(
+            ::core::arch::x86::__m128,
+            ::core::arch::x86::__m128d,
+            ::core::arch::x86::__m128i,
+            ::core::arch::x86::__m256,
+            ::core::arch::x86::__m256d,
+            ::core::arch::x86::__m256i,
+            ::core::arch::x86::__m512,
+            ::core::arch::x86::__m512d,
+            ::core::arch::x86::__m512i
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        $callback!(
+            ::core::arch::x86_64::__m128,
+            ::core::arch::x86_64::__m128d,
+            ::core::arch::x86_64::__m128i,
+            ::core::arch::x86_64::__m256,
+            ::core::arch::x86_64::__m256d,
+            ::core::arch::x86_64::__m256i,
+            ::core::arch::x86_64::__m512,
+            ::core::arch::x86_64::__m512d,
+            ::core::arch::x86_64::__m512i
+        );
+
+        #[cfg(target_arch = "aarch64")]
+        $callback!(
+            ::core::arch::aarch64::int8x8_t,
+            ::core::arch::aarch64::int8x16_t,
+            ::core::arch::aarch64::int16x4_t,
+            ::core::arch::aarch64::int16x8_t,
+            ::core::arch::aarch64::int32x2_t,
+            ::core::arch::aarch64::int32x4_t,
+            ::core::arch::aarch64::int64x1_t,
+            ::core::arch::aarch64::int64x2_t,
+            ::core::arch::aarch64::uint8x8_t,
+            ::core::arch::aarch64::uint8x16_t,
+            ::core::arch::aarch64::uint16x4_t,
+            ::core::arch::aarch64::uint16x8_t,
+            ::core::arch::aarch64::uint32x2_t,
+            ::core::arch::aarch64::uint32x4_t,
+            ::core::arch::aarch64::uint64x1_t,
+            ::core::arch::aarch64::uint64x2_t,
+            ::core::arch::aarch64::float32x2_t,
+            ::core::arch::aarch64::float32x4_t,
+            ::core::arch::aarch64::float64x1_t,
+            ::core::arch::aarch64::float64x2_t,
+            ::core::arch::aarch64::poly8x8_t,
+            ::core::arch::aarch64::poly8x16_t,
+            ::core::arch::aarch64::poly16x4_t,
+            ::core::arch::aarch64::poly16x8_t,
+            ::core::arch::aarch64::poly64x1_t,
+            ::core::arch::aarch64::poly64x2_t
+        );
+    };
+}
+
+pub(crate) use impl_for_simd;
@@ -1,4 +1,7 @@
-use crate::{macros::impl_for_atomic, Pointee};
+use crate::{
+    macros::{impl_for_atomic, impl_for_fn_ptr, impl_for_simd},
+    Pointee, Unaligned,
+};
 
 // #[doc(inline)]
 // pub use crate::no_uninit_in_option::*;
@@ -7,6 +10,10 @@ use crate::{macros::impl_for_atomic, Pointee};
 ///
 /// This trait makes no guarantees about whether these bytes can be safely accessed,
 /// for additional guarantees use the [`crate::Freeze`] trait.
+///
+/// # Safety
+///
+/// No valid `Self` may contain uninitialized bytes, including padding.
 pub unsafe trait NoUninit {}
 
 unsafe impl<T: NoUninit> NoUninit for [T] {}
@@ -35,6 +42,9 @@ unsafe impl<T: NoUninit + ?Sized> NoUninit for core::cell::Cell<T> {}
 
 unsafe impl<T: ?Sized> NoUninit for core::marker::PhantomData<T> {}
 
+// SAFETY: `Pin` is `#[repr(transparent)]`.
+unsafe impl<P: NoUninit> NoUninit for core::pin::Pin<P> {}
+
 macro_rules! no_uninit {
     ($($ty:ty),*) => {
         $(
@@ -60,4 +70,49 @@ no_uninit!(str);
 no_uninit!(core::cmp::Ordering);
 no_uninit!(core::marker::PhantomPinned);
 
+// SAFETY: There are no values of `Infallible`, so there are no uninitialized bytes.
+no_uninit!(core::convert::Infallible);
+
+// SAFETY: A tuple of `Unaligned` types has an alignment of `1`, so there is no padding.
+
+macro_rules! no_uninit_tuple {
+    (
+        $(
+            (
+                $( $name:ident
+                    $( : ( $( $rest:tt )* ) )?
+                ),*
+            )
+        ),* $(,)?
+    ) => {
+        $(
+            unsafe impl<$($name: NoUninit + Unaligned $( $($rest)* )?),*> NoUninit for ($($name,)*) {}
+        )*
+    };
+}
+
+no_uninit_tuple!(
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5: (+ ?Sized)),
+    (T0, T1, T2, T3, T4: (+ ?Sized)),
+    (T0, T1, T2, T3: (+ ?Sized)),
+    (T0, T1, T2: (+ ?Sized)),
+    (T0, T1: (+ ?Sized)),
+    (T0: (+ ?Sized)),
+);
+
 impl_for_atomic!(unsafe impl NoUninit);
+
+// SAFETY: Function pointers are addresses, and are always initialized.
+impl_for_fn_ptr!(unsafe impl NoUninit);
+
+impl_for_simd!(no_uninit);
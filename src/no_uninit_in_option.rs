@@ -1,6 +1,42 @@
-use crate::no_uninit::NoUninit;
+use crate::{macros::impl_for_fn_ptr, no_uninit::NoUninit};
 
 /// Trait for types that contain no uninitialized in an option.
+///
+/// # Safety
+///
+/// No valid `Option<Self>`, including [`None`], may contain uninitialized bytes.
 pub unsafe trait NoUninitInOption: Sized + NoUninit {}
 
 unsafe impl<T: NoUninitInOption> NoUninit for Option<T> {}
+
+// SAFETY: The `None` of all of these is a niche value, so it is fully initialized.
+//
+//         This only holds for thin pointers, as a `None` fat pointer leaves its metadata uninitialized.
+
+macro_rules! nonzero {
+    ($($prim:ident),*) => {
+        $(
+            unsafe impl NoUninitInOption for core::num::NonZero<$prim> {}
+        )*
+    };
+}
+
+nonzero!(u8, u16, u32, u64, u128, usize);
+nonzero!(i8, i16, i32, i64, i128, isize);
+
+unsafe impl NoUninitInOption for bool {}
+unsafe impl NoUninitInOption for char {}
+unsafe impl NoUninitInOption for core::cmp::Ordering {}
+
+unsafe impl<T> NoUninitInOption for core::ptr::NonNull<T> {}
+unsafe impl<T> NoUninitInOption for &T {}
+unsafe impl<T> NoUninitInOption for &mut T {}
+
+impl_for_fn_ptr!(unsafe impl NoUninitInOption);
+
+unsafe impl<T: NoUninitInOption> NoUninitInOption for core::mem::ManuallyDrop<T> {}
+
+unsafe impl<T: NoUninitInOption> NoUninitInOption for core::num::Saturating<T> {}
+unsafe impl<T: NoUninitInOption> NoUninitInOption for core::num::Wrapping<T> {}
+
+unsafe impl<T: NoUninitInOption> NoUninitInOption for core::cmp::Reverse<T> {}
@@ -1,12 +1,38 @@
 use core::{
+    alloc::Layout,
     cell::{Cell, UnsafeCell},
     fmt, hash,
+    marker::PhantomData,
     mem::{transmute, ManuallyDrop},
     ptr::{self, NonNull},
 };
 
 use crate::{Freeze, NoUninit};
 
+mod layout;
+pub use layout::*;
+
+mod raw_slice;
+pub use raw_slice::*;
+
+mod cast;
+pub use cast::*;
+
+mod null;
+pub use null::*;
+
+mod provenance;
+pub use provenance::*;
+
+mod words;
+pub use words::*;
+
+mod unsize;
+pub use unsize::*;
+
+mod tagged;
+pub use tagged::*;
+
 /// Enum that determines whether the pointer metadata for a given type is
 /// stored before, or after the address.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -37,6 +63,27 @@ impl MetadataPosition {
             }
         }
     }
+
+    /// Get the metadata position of trait objects.
+    ///
+    /// All trait object pointers share the same layout, so this is detected from a `*const dyn Any`.
+    #[inline]
+    #[must_use]
+    pub const fn for_dyn() -> Self {
+        const {
+            let ptr = ptr::null::<()>() as *const dyn core::any::Any;
+
+            let [a, b]: [*const (); 2] = unsafe { transmute(ptr) };
+
+            match [a.is_null(), b.is_null()] {
+                // If the vtable is first, then we know the metadata is before.
+                [false, true] => MetadataPosition::Before,
+                // If the vtable is second, then we know the metadata is after.
+                [true, false] => MetadataPosition::After,
+                _ => unreachable!(),
+            }
+        }
+    }
 }
 
 // /// Trait for types that are used as the metadata for some pointers.
@@ -76,40 +123,325 @@ unsafe impl Pointee for str {
     const METADATA_POSITION: MetadataPosition = <[u8] as Pointee>::METADATA_POSITION;
 }
 
-// SAFETY: I'm pretty sure these will always be correct.
-//
-//         Pretty... Sure.
+/// Implement [`Pointee`], [`DstLayout`], [`KnownAlign`], and [`SliceLike`] for a
+/// `#[repr(transparent)]` wrapper around a dynamically sized type, forwarding them from the
+/// wrapped type.
+///
+/// ```
+/// use legume::ptr::{DstLayout, KnownAlign};
+///
+/// #[repr(transparent)]
+/// struct Path([u8]);
+///
+/// legume::transparent_pointee!(unsafe impl Path => [u8]);
+///
+/// assert_eq!(<Path as KnownAlign>::ALIGN, 1);
+/// assert_eq!(Path::layout_for(3).unwrap().size(), 3);
+/// ```
+///
+/// Generic wrappers that accept any `T: ?Sized` can instead be written as
+/// `unsafe impl<T: ?Sized> Wrapper<T>`, which covers `Wrapper<[T]>` and `Wrapper<str>`, and
+/// also implements [`Unsize`] from `Wrapper<T>` to `Wrapper<U>` when `T: Unsize<U>`.
+///
+/// # Safety
+///
+/// The wrapper must be `#[repr(transparent)]` over the wrapped type, or otherwise guarantee
+/// that it has the same layout and pointer metadata as it.
+#[macro_export]
+macro_rules! transparent_pointee {
+    (@impl [$($gen:ident),*] $ty:ty => $inner:ty) => {
+        unsafe impl<$($gen),*> $crate::ptr::Pointee for $ty {
+            type Metadata = <$inner as $crate::ptr::Pointee>::Metadata;
+
+            const METADATA_POSITION: $crate::ptr::MetadataPosition =
+                <$inner as $crate::ptr::Pointee>::METADATA_POSITION;
+        }
+
+        unsafe impl<$($gen),*> $crate::ptr::DstLayout for $ty {
+            #[inline]
+            fn layout_for(
+                meta: <Self as $crate::ptr::Pointee>::Metadata,
+            ) -> ::core::result::Result<::core::alloc::Layout, ::core::alloc::LayoutError> {
+                <$inner as $crate::ptr::DstLayout>::layout_for(meta)
+            }
+        }
+
+        // The bounds are higher-ranked so they are not rejected as trivially false, such as for
+        // wrappers around trait objects.
+        unsafe impl<$($gen),*> $crate::ptr::KnownAlign for $ty
+        where
+            for<'__legume> $inner: $crate::ptr::KnownAlign,
+        {
+            const ALIGN: usize = <$inner as $crate::ptr::KnownAlign>::ALIGN;
+        }
+
+        unsafe impl<$($gen),*> $crate::ptr::SliceLike for $ty
+        where
+            for<'__legume> $inner: $crate::ptr::SliceLike,
+        {
+            type Elem = <$inner as $crate::ptr::SliceLike>::Elem;
+        }
+    };
+
+    (
+        unsafe impl<$param:ident: ?Sized> $wrapper:ident<$arg:ident> $(; $($rest:tt)*)?
+    ) => {
+        $crate::transparent_pointee! {
+            unsafe impl<T> $wrapper<[T]> => [T];
+            unsafe impl $wrapper<str> => str;
+        }
+
+        unsafe impl<T, U> $crate::ptr::Unsize<$wrapper<U>> for $wrapper<T>
+        where
+            T: $crate::ptr::Unsize<U>,
+            U: $crate::ptr::Pointee + ?Sized,
+            $wrapper<U>: $crate::ptr::Pointee<Metadata = <U as $crate::ptr::Pointee>::Metadata>,
+        {
+            #[inline]
+            fn unsize_meta() -> <U as $crate::ptr::Pointee>::Metadata {
+                <T as $crate::ptr::Unsize<U>>::unsize_meta()
+            }
+        }
+
+        $($crate::transparent_pointee!($($rest)*);)?
+    };
+
+    (
+        unsafe impl<$($gen:ident),* $(,)?> $ty:ty => $inner:ty $(; $($rest:tt)*)?
+    ) => {
+        $crate::transparent_pointee!(@impl [$($gen),*] $ty => $inner);
+        $($crate::transparent_pointee!($($rest)*);)?
+    };
+
+    (
+        unsafe impl $ty:ty => $inner:ty $(; $($rest:tt)*)?
+    ) => {
+        $crate::transparent_pointee!(@impl [] $ty => $inner);
+        $($crate::transparent_pointee!($($rest)*);)?
+    };
+
+    () => {};
+}
+
+// SAFETY: These wrappers are all `#[repr(transparent)]`, or documented to have the same
+//         layout as what they wrap.
+
+// NOTE: `Wrapping`, `Saturating`, `Reverse`, and `Unalign` require `T: Sized`, so they can
+//       never wrap a dynamically sized type, and are covered by the blanket implementations.
+
+transparent_pointee! {
+    unsafe impl<T: ?Sized> ManuallyDrop<T>;
+    unsafe impl<T: ?Sized> UnsafeCell<T>;
+    unsafe impl<T: ?Sized> Cell<T>;
+}
+
+/// The metadata of a pointer to a trait object, which is a pointer to its vtable.
+///
+/// The layout of vtables is not specified by the language, so reading the size and alignment
+/// from one is best-effort. Before the first read, the vtables of a few known types are compared
+/// against what the compiler reports, and a panic occurs if they disagree. Miri treats vtables
+/// as opaque, so reading them is not supported there.
+#[repr(transparent)]
+pub struct DynMetadata<Dyn: ?Sized> {
+    /// This is not a reference, as vtables are not guaranteed to be ordinary memory.
+    vtable: NonNull<VTable>,
+    _marker: PhantomData<fn() -> *const Dyn>,
+}
+
+/// The start of every vtable, as currently emitted by the compiler.
+#[repr(C)]
+struct VTable {
+    drop_in_place: *const (),
+    size_of: usize,
+    align_of: usize,
+}
+
+/// Check that vtables start with a [`VTable`], by comparing the vtables of known types against
+/// what the compiler reports for them.
+#[cold]
+fn check_vtable_layout() -> bool {
+    fn check<T: core::any::Any>(value: &T) -> bool {
+        let value: &dyn core::any::Any = value;
+        // SAFETY: Vtables are static, and this is only a layout check.
+        let vtable = unsafe { metadata(ptr::from_ref(value)).vtable.as_ref() };
+
+        vtable.size_of == size_of_val(value) && vtable.align_of == align_of_val(value)
+    }
+
+    check(&0_u64) && check(&[0_u8; 3])
+}
+
+/// Panic if vtables do not start with a [`VTable`].
+#[inline]
+fn assert_vtable_layout() {
+    #[cfg(target_has_atomic = "8")]
+    {
+        use core::sync::atomic::{AtomicBool, Ordering};
+
+        static CHECKED: AtomicBool = AtomicBool::new(false);
+
+        if !CHECKED.load(Ordering::Relaxed) {
+            assert!(check_vtable_layout(), "unsupported vtable layout");
+
+            CHECKED.store(true, Ordering::Relaxed);
+        }
+    }
 
-unsafe impl<T> Pointee for ManuallyDrop<[T]> {
-    type Metadata = <[T] as Pointee>::Metadata;
-    const METADATA_POSITION: MetadataPosition = <[T] as Pointee>::METADATA_POSITION;
+    #[cfg(not(target_has_atomic = "8"))]
+    assert!(check_vtable_layout(), "unsupported vtable layout");
 }
 
-unsafe impl Pointee for ManuallyDrop<str> {
-    type Metadata = <str as Pointee>::Metadata;
-    const METADATA_POSITION: MetadataPosition = <str as Pointee>::METADATA_POSITION;
+impl<Dyn: ?Sized> DynMetadata<Dyn> {
+    /// Get the vtable, after checking that its layout is understood.
+    #[inline]
+    fn vtable(self) -> &'static VTable {
+        assert_vtable_layout();
+
+        // SAFETY: Vtables are static, and start with a `VTable` as was just checked.
+        unsafe { self.vtable.as_ref() }
+    }
+
+    /// Get the size of the type associated with this vtable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the layout of vtables is not the one this crate expects.
+    #[inline]
+    #[must_use]
+    pub fn size_of(self) -> usize {
+        self.vtable().size_of
+    }
+
+    /// Get the alignment of the type associated with this vtable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the layout of vtables is not the one this crate expects.
+    #[inline]
+    #[must_use]
+    pub fn align_of(self) -> usize {
+        self.vtable().align_of
+    }
+
+    /// Get the layout of the type associated with this vtable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the layout of vtables is not the one this crate expects.
+    #[inline]
+    #[must_use]
+    pub fn layout(self) -> Layout {
+        let vtable = self.vtable();
+
+        match Layout::from_size_align(vtable.size_of, vtable.align_of) {
+            Ok(layout) => layout,
+            Err(_) => panic!("vtable contained an invalid layout"),
+        }
+    }
 }
 
-unsafe impl<T> Pointee for UnsafeCell<[T]> {
-    type Metadata = <[T] as Pointee>::Metadata;
-    const METADATA_POSITION: MetadataPosition = <[T] as Pointee>::METADATA_POSITION;
+// SAFETY: Vtables are never mutated, so they can be shared between threads.
+unsafe impl<Dyn: ?Sized> Send for DynMetadata<Dyn> {}
+unsafe impl<Dyn: ?Sized> Sync for DynMetadata<Dyn> {}
+
+// SAFETY: This is just a reference.
+unsafe impl<Dyn: ?Sized> NoUninit for DynMetadata<Dyn> {}
+unsafe impl<Dyn: ?Sized> Freeze for DynMetadata<Dyn> {}
+
+impl<Dyn: ?Sized> fmt::Debug for DynMetadata<Dyn> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DynMetadata").field(&self.vtable).finish()
+    }
 }
 
-unsafe impl Pointee for UnsafeCell<str> {
-    type Metadata = <str as Pointee>::Metadata;
-    const METADATA_POSITION: MetadataPosition = <str as Pointee>::METADATA_POSITION;
+impl<Dyn: ?Sized> Clone for DynMetadata<Dyn> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Dyn: ?Sized> Copy for DynMetadata<Dyn> {}
+
+impl<Dyn: ?Sized> PartialEq for DynMetadata<Dyn> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.vtable == other.vtable
+    }
+}
+
+impl<Dyn: ?Sized> Eq for DynMetadata<Dyn> {}
+
+impl<Dyn: ?Sized> PartialOrd for DynMetadata<Dyn> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Dyn: ?Sized> Ord for DynMetadata<Dyn> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.vtable.cmp(&other.vtable)
+    }
 }
 
-unsafe impl<T> Pointee for Cell<[T]> {
-    type Metadata = <[T] as Pointee>::Metadata;
-    const METADATA_POSITION: MetadataPosition = <[T] as Pointee>::METADATA_POSITION;
+impl<Dyn: ?Sized> hash::Hash for DynMetadata<Dyn> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.vtable.hash(state);
+    }
 }
 
-unsafe impl Pointee for Cell<str> {
-    type Metadata = <str as Pointee>::Metadata;
-    const METADATA_POSITION: MetadataPosition = <str as Pointee>::METADATA_POSITION;
+/// Implement [`Pointee`] and [`DstLayout`] for a trait object, along with its `+ Send`, `+ Sync`,
+/// and `+ Send + Sync` variants.
+///
+/// Generic traits must list their generic parameters first, as in `dyn_pointee!(impl<T> Trait<T>)`.
+#[macro_export]
+macro_rules! dyn_pointee {
+    (@impl [$($gen:ident),*] $ty:ty) => {
+        unsafe impl<'__legume $(, $gen)*> $crate::ptr::Pointee for $ty {
+            type Metadata = $crate::ptr::DynMetadata<Self>;
+
+            const METADATA_POSITION: $crate::ptr::MetadataPosition =
+                $crate::ptr::MetadataPosition::for_dyn();
+        }
+
+        unsafe impl<'__legume $(, $gen)*> $crate::ptr::DstLayout for $ty {
+            #[inline]
+            fn layout_for(
+                meta: $crate::ptr::DynMetadata<Self>,
+            ) -> ::core::result::Result<::core::alloc::Layout, ::core::alloc::LayoutError> {
+                ::core::result::Result::Ok(meta.layout())
+            }
+        }
+    };
+
+    (
+        impl<$($gen:ident),* $(,)?> $trait:path
+    ) => {
+        $crate::dyn_pointee!(@impl [$($gen),*] dyn $trait + '__legume);
+        $crate::dyn_pointee!(@impl [$($gen),*] dyn $trait + ::core::marker::Send + '__legume);
+        $crate::dyn_pointee!(@impl [$($gen),*] dyn $trait + ::core::marker::Sync + '__legume);
+        $crate::dyn_pointee!(
+            @impl [$($gen),*]
+            dyn $trait + ::core::marker::Send + ::core::marker::Sync + '__legume
+        );
+    };
+
+    (
+        $trait:path
+    ) => {
+        $crate::dyn_pointee!(impl<> $trait);
+    };
 }
 
+dyn_pointee!(core::any::Any);
+dyn_pointee!(core::fmt::Debug);
+dyn_pointee!(core::fmt::Display);
+dyn_pointee!(core::error::Error);
+
 /// Trait for types that are accessed through thin pointers.
 pub trait Thin: Pointee<Metadata = ()> {}
 
@@ -0,0 +1,159 @@
+use core::{fmt, ptr::NonNull};
+
+use super::{
+    from_raw_parts, from_raw_parts_mut, from_raw_parts_nonnull, metadata, to_raw_parts,
+    to_raw_parts_mut, to_raw_parts_nonnull, Pointee,
+};
+
+/// Trait for slice-like types, whose pointer metadata is the number of elements they contain.
+///
+/// # Safety
+///
+/// A pointer to `Self` with a metadata of `len` must point to `len` contiguous elements
+/// of type `Self::Elem`, with the same layout as `[Self::Elem]`.
+pub unsafe trait SliceLike: Pointee<Metadata = usize> {
+    /// The type of the elements.
+    type Elem;
+}
+
+unsafe impl<T> SliceLike for [T] {
+    type Elem = T;
+}
+
+unsafe impl SliceLike for str {
+    type Elem = u8;
+}
+
+/// Error for when the length of a slice-like pointer can not be scaled to another element type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CastSliceError {
+    /// The length in bytes does not fit in a `usize`.
+    Overflow,
+    /// The length in bytes is not a multiple of the size of the target element.
+    Remainder,
+    /// The target element is zero-sized, while the source element is not.
+    ZeroSized,
+}
+
+impl fmt::Display for CastSliceError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Overflow => "length in bytes overflowed",
+            Self::Remainder => "length in bytes was not a multiple of the target element size",
+            Self::ZeroSized => "target element was zero-sized",
+        })
+    }
+}
+
+impl core::error::Error for CastSliceError {}
+
+/// Scale the length of a `T` to the length of a `U` covering the same bytes.
+#[inline]
+const fn scale_len<T: SliceLike + ?Sized, U: SliceLike + ?Sized>(
+    len: usize,
+) -> Result<usize, CastSliceError> {
+    let src = size_of::<T::Elem>();
+    let dst = size_of::<U::Elem>();
+
+    if src == dst {
+        return Ok(len);
+    }
+
+    let bytes = match len.checked_mul(src) {
+        Some(bytes) => bytes,
+        None => return Err(CastSliceError::Overflow),
+    };
+
+    if dst == 0 {
+        return Err(CastSliceError::ZeroSized);
+    }
+
+    if bytes % dst != 0 {
+        return Err(CastSliceError::Remainder);
+    }
+
+    Ok(bytes / dst)
+}
+
+/// Cast a pointer to a slice-like `T` to a pointer to a slice-like `U` covering the same bytes.
+///
+/// This does not check that the address is aligned for `U`.
+#[inline]
+pub const fn cast_slice_ptr<T: SliceLike + ?Sized, U: SliceLike + ?Sized>(
+    ptr: *const T,
+) -> Result<*const U, CastSliceError> {
+    let (addr, len) = to_raw_parts(ptr);
+
+    match scale_len::<T, U>(len) {
+        Ok(len) => Ok(from_raw_parts(addr, len)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Cast a mutable pointer to a slice-like `T` to a mutable pointer to a slice-like `U` covering
+/// the same bytes.
+///
+/// This does not check that the address is aligned for `U`.
+#[inline]
+pub const fn cast_slice_ptr_mut<T: SliceLike + ?Sized, U: SliceLike + ?Sized>(
+    ptr: *mut T,
+) -> Result<*mut U, CastSliceError> {
+    let (addr, len) = to_raw_parts_mut(ptr);
+
+    match scale_len::<T, U>(len) {
+        Ok(len) => Ok(from_raw_parts_mut(addr, len)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Cast a [`NonNull`] pointer to a slice-like `T` to a [`NonNull`] pointer to a slice-like `U`
+/// covering the same bytes.
+///
+/// This does not check that the address is aligned for `U`.
+#[inline]
+pub const fn cast_slice_nonnull<T: SliceLike + ?Sized, U: SliceLike + ?Sized>(
+    ptr: NonNull<T>,
+) -> Result<NonNull<U>, CastSliceError> {
+    let (addr, len) = to_raw_parts_nonnull(ptr);
+
+    match scale_len::<T, U>(len) {
+        Ok(len) => Ok(from_raw_parts_nonnull(addr, len)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Cast a pointer to a `T` to a pointer to a `U` with the same address and metadata.
+#[inline]
+#[must_use]
+pub const fn cast_same_meta<T: Pointee + ?Sized, U: Pointee<Metadata = T::Metadata> + ?Sized>(
+    ptr: *const T,
+) -> *const U {
+    from_raw_parts(ptr.cast(), metadata(ptr))
+}
+
+/// Cast a mutable pointer to a `T` to a mutable pointer to a `U` with the same address
+/// and metadata.
+#[inline]
+#[must_use]
+pub const fn cast_same_meta_mut<
+    T: Pointee + ?Sized,
+    U: Pointee<Metadata = T::Metadata> + ?Sized,
+>(
+    ptr: *mut T,
+) -> *mut U {
+    from_raw_parts_mut(ptr.cast(), metadata(ptr))
+}
+
+/// Cast a [`NonNull`] pointer to a `T` to a [`NonNull`] pointer to a `U` with the same address
+/// and metadata.
+#[inline]
+#[must_use]
+pub const fn cast_same_meta_nonnull<
+    T: Pointee + ?Sized,
+    U: Pointee<Metadata = T::Metadata> + ?Sized,
+>(
+    ptr: NonNull<T>,
+) -> NonNull<U> {
+    from_raw_parts_nonnull(ptr.cast(), metadata(ptr.as_ptr()))
+}
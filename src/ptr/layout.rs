@@ -0,0 +1,81 @@
+use core::alloc::{Layout, LayoutError};
+
+use super::{metadata, Pointee};
+
+/// Trait for [`Pointee`] types whose layout can be computed from their pointer metadata.
+///
+/// # Safety
+///
+/// When [`DstLayout::layout_for`] succeeds, it must return the layout of a `Self`
+/// behind a pointer with the given metadata.
+pub unsafe trait DstLayout: Pointee {
+    /// Get the layout of `Self` for the given pointer metadata.
+    fn layout_for(meta: Self::Metadata) -> Result<Layout, LayoutError>;
+}
+
+unsafe impl<T> DstLayout for T {
+    #[inline]
+    fn layout_for((): ()) -> Result<Layout, LayoutError> {
+        Ok(Layout::new::<T>())
+    }
+}
+
+unsafe impl<T> DstLayout for [T] {
+    #[inline]
+    fn layout_for(len: usize) -> Result<Layout, LayoutError> {
+        Layout::array::<T>(len)
+    }
+}
+
+unsafe impl DstLayout for str {
+    #[inline]
+    fn layout_for(len: usize) -> Result<Layout, LayoutError> {
+        Layout::array::<u8>(len)
+    }
+}
+
+/// Trait for [`Pointee`] types whose alignment does not depend on their pointer metadata.
+///
+/// # Safety
+///
+/// `Self::ALIGN` must be the alignment of a `Self` behind a pointer with any metadata.
+pub unsafe trait KnownAlign: Pointee {
+    /// The alignment of `Self`.
+    const ALIGN: usize;
+}
+
+unsafe impl<T> KnownAlign for T {
+    const ALIGN: usize = align_of::<T>();
+}
+
+unsafe impl<T> KnownAlign for [T] {
+    const ALIGN: usize = align_of::<T>();
+}
+
+unsafe impl KnownAlign for str {
+    const ALIGN: usize = align_of::<u8>();
+}
+
+/// Get the layout of a `T` for the given pointer metadata.
+#[inline]
+pub fn layout_for_meta<T: DstLayout + ?Sized>(meta: T::Metadata) -> Result<Layout, LayoutError> {
+    T::layout_for(meta)
+}
+
+/// Get the size of the value pointed to by `ptr`, without needing a valid pointer.
+#[inline]
+pub fn size_of_val_raw<T: DstLayout + ?Sized>(ptr: *const T) -> Result<usize, LayoutError> {
+    match T::layout_for(metadata(ptr)) {
+        Ok(layout) => Ok(layout.size()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Get the alignment of the value pointed to by `ptr`, without needing a valid pointer.
+#[inline]
+pub fn align_of_val_raw<T: DstLayout + ?Sized>(ptr: *const T) -> Result<usize, LayoutError> {
+    match T::layout_for(metadata(ptr)) {
+        Ok(layout) => Ok(layout.align()),
+        Err(err) => Err(err),
+    }
+}
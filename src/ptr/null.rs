@@ -0,0 +1,48 @@
+use core::ptr::{self, NonNull};
+
+use super::{from_raw_parts, from_raw_parts_mut, from_raw_parts_nonnull, KnownAlign, Pointee};
+use crate::Zeroable;
+
+/// Create a null pointer to a `T`, with zeroed metadata.
+#[inline]
+#[must_use]
+pub const fn null<T: Pointee<Metadata: Zeroable> + ?Sized>() -> *const T {
+    // SAFETY: The metadata is `Zeroable`.
+    from_raw_parts(ptr::null(), unsafe { core::mem::zeroed() })
+}
+
+/// Create a null mutable pointer to a `T`, with zeroed metadata.
+#[inline]
+#[must_use]
+pub const fn null_mut<T: Pointee<Metadata: Zeroable> + ?Sized>() -> *mut T {
+    // SAFETY: The metadata is `Zeroable`.
+    from_raw_parts_mut(ptr::null_mut(), unsafe { core::mem::zeroed() })
+}
+
+/// Create a dangling, but well aligned pointer to a `T` with the given metadata.
+///
+/// For slice-like types, this is aligned for the element type.
+#[inline]
+#[must_use]
+pub const fn dangling<T: KnownAlign + ?Sized>(meta: T::Metadata) -> *const T {
+    from_raw_parts(ptr::without_provenance(T::ALIGN), meta)
+}
+
+/// Create a dangling, but well aligned [`NonNull`] pointer to a `T` with the given metadata.
+///
+/// For slice-like types, this is aligned for the element type.
+#[inline]
+#[must_use]
+pub const fn dangling_nonnull<T: KnownAlign + ?Sized>(meta: T::Metadata) -> NonNull<T> {
+    // SAFETY: Alignments are never zero.
+    let addr = unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(T::ALIGN)) };
+
+    from_raw_parts_nonnull(addr, meta)
+}
+
+/// Get whether the address of a pointer is null, ignoring its metadata.
+#[inline]
+#[must_use]
+pub const fn is_null<T: Pointee + ?Sized>(ptr: *const T) -> bool {
+    ptr.cast::<()>().is_null()
+}
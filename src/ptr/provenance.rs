@@ -0,0 +1,83 @@
+use super::{from_raw_parts, from_raw_parts_mut, to_raw_parts, to_raw_parts_mut, Pointee};
+
+/// Get the address of a pointer, ignoring its metadata and provenance.
+///
+/// See [`pointer::addr`].
+#[inline]
+#[must_use]
+pub fn addr<T: Pointee + ?Sized>(ptr: *const T) -> usize {
+    to_raw_parts(ptr).0.addr()
+}
+
+/// Create a pointer with the given address, and the provenance and metadata of `ptr`.
+///
+/// See [`pointer::with_addr`].
+#[inline]
+#[must_use]
+pub fn with_addr<T: Pointee + ?Sized>(ptr: *const T, addr: usize) -> *const T {
+    let (ptr, meta) = to_raw_parts(ptr);
+
+    from_raw_parts(ptr.with_addr(addr), meta)
+}
+
+/// Create a mutable pointer with the given address, and the provenance and metadata of `ptr`.
+///
+/// See [`pointer::with_addr`].
+#[inline]
+#[must_use]
+pub fn with_addr_mut<T: Pointee + ?Sized>(ptr: *mut T, addr: usize) -> *mut T {
+    let (ptr, meta) = to_raw_parts_mut(ptr);
+
+    from_raw_parts_mut(ptr.with_addr(addr), meta)
+}
+
+/// Create a pointer by mapping the address of `ptr`, keeping its provenance and metadata.
+///
+/// See [`pointer::map_addr`].
+#[inline]
+#[must_use]
+pub fn map_addr<T: Pointee + ?Sized>(ptr: *const T, f: impl FnOnce(usize) -> usize) -> *const T {
+    let (ptr, meta) = to_raw_parts(ptr);
+
+    from_raw_parts(ptr.map_addr(f), meta)
+}
+
+/// Create a mutable pointer by mapping the address of `ptr`, keeping its provenance and metadata.
+///
+/// See [`pointer::map_addr`].
+#[inline]
+#[must_use]
+pub fn map_addr_mut<T: Pointee + ?Sized>(ptr: *mut T, f: impl FnOnce(usize) -> usize) -> *mut T {
+    let (ptr, meta) = to_raw_parts_mut(ptr);
+
+    from_raw_parts_mut(ptr.map_addr(f), meta)
+}
+
+/// Expose the provenance of a pointer and get its address, ignoring its metadata.
+///
+/// See [`pointer::expose_provenance`].
+#[inline]
+#[must_use]
+pub fn expose_provenance<T: Pointee + ?Sized>(ptr: *const T) -> usize {
+    to_raw_parts(ptr).0.expose_provenance()
+}
+
+/// Create a pointer to a `T` from an address with previously exposed provenance,
+/// and the pointer metadata.
+///
+/// See [`core::ptr::with_exposed_provenance`].
+#[inline]
+#[must_use]
+pub fn with_exposed_provenance<T: Pointee + ?Sized>(addr: usize, meta: T::Metadata) -> *const T {
+    from_raw_parts(core::ptr::with_exposed_provenance(addr), meta)
+}
+
+/// Create a mutable pointer to a `T` from an address with previously exposed provenance,
+/// and the pointer metadata.
+///
+/// See [`core::ptr::with_exposed_provenance_mut`].
+#[inline]
+#[must_use]
+pub fn with_exposed_provenance_mut<T: Pointee + ?Sized>(addr: usize, meta: T::Metadata) -> *mut T {
+    from_raw_parts_mut(core::ptr::with_exposed_provenance_mut(addr), meta)
+}
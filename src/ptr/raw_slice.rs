@@ -0,0 +1,306 @@
+use core::{fmt, ptr::NonNull, str::Utf8Error};
+
+use super::{from_raw_parts, from_raw_parts_mut, from_raw_parts_nonnull, to_raw_parts, Pointee};
+use crate::{CheckBits, Freeze, NoUninit, Zeroable};
+
+/// A `#[repr(C)]` pointer and length pair, for passing `*const [T]` across FFI.
+#[repr(C)]
+pub struct RawSlice<T> {
+    /// Pointer to the first element.
+    pub ptr: *const T,
+    /// The number of elements.
+    pub len: usize,
+}
+
+/// A `#[repr(C)]` pointer and length pair, for passing `*mut [T]` across FFI.
+#[repr(C)]
+pub struct RawSliceMut<T> {
+    /// Pointer to the first element.
+    pub ptr: *mut T,
+    /// The number of elements.
+    pub len: usize,
+}
+
+/// A `#[repr(C)]` pointer and length pair, for passing `*const str` across FFI.
+#[repr(C)]
+pub struct RawStr {
+    /// Pointer to the first byte.
+    pub ptr: *const u8,
+    /// The number of bytes.
+    pub len: usize,
+}
+
+impl<T> RawSlice<T> {
+    /// Create a [`RawSlice`] from a pointer and a length.
+    #[inline]
+    #[must_use]
+    pub const fn new(ptr: *const T, len: usize) -> Self {
+        Self { ptr, len }
+    }
+
+    /// Create a [`RawSlice`] from the address and metadata of a pointer.
+    #[inline]
+    #[must_use]
+    pub const fn from_ptr<P: Pointee<Metadata = usize> + ?Sized>(ptr: *const P) -> Self {
+        let (addr, len) = to_raw_parts(ptr);
+
+        Self::new(addr.cast(), len)
+    }
+
+    /// Create a pointer to a `P` from the pointer and length.
+    #[inline]
+    #[must_use]
+    pub const fn to_ptr<P: Pointee<Metadata = usize> + ?Sized>(self) -> *const P {
+        from_raw_parts(self.ptr.cast(), self.len)
+    }
+
+    /// Get a slice reference from the pointer and length, checking that every element is valid.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null and aligned for `T`, even if `len` is zero. It must be valid for
+    /// reads of `len` elements, and the memory must not be mutated for the lifetime `'a`.
+    #[inline]
+    pub unsafe fn as_slice<'a>(self) -> Result<&'a [T], T::Error>
+    where
+        T: CheckBits<Bits: Sized>,
+    {
+        // SAFETY: The caller ensures that `ptr` is non-null.
+        let bits = from_raw_parts_nonnull::<[T::Bits]>(
+            unsafe { NonNull::new_unchecked(self.ptr.cast_mut().cast()) },
+            self.len,
+        );
+
+        // SAFETY: The caller ensures that `bits` is valid for reads.
+        match unsafe { <[T]>::check_bits(bits) } {
+            // SAFETY: The caller ensures that the memory lives for `'a` and is not mutated.
+            Ok(ptr) => Ok(unsafe { ptr.as_ref() }),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<T> RawSliceMut<T> {
+    /// Create a [`RawSliceMut`] from a pointer and a length.
+    #[inline]
+    #[must_use]
+    pub const fn new(ptr: *mut T, len: usize) -> Self {
+        Self { ptr, len }
+    }
+
+    /// Create a [`RawSliceMut`] from the address and metadata of a pointer.
+    #[inline]
+    #[must_use]
+    pub const fn from_ptr<P: Pointee<Metadata = usize> + ?Sized>(ptr: *mut P) -> Self {
+        let (addr, len) = to_raw_parts(ptr);
+
+        Self::new(addr.cast_mut().cast(), len)
+    }
+
+    /// Create a mutable pointer to a `P` from the pointer and length.
+    #[inline]
+    #[must_use]
+    pub const fn to_ptr<P: Pointee<Metadata = usize> + ?Sized>(self) -> *mut P {
+        from_raw_parts_mut(self.ptr.cast(), self.len)
+    }
+
+    /// Get a [`RawSlice`] with the same pointer and length.
+    #[inline]
+    #[must_use]
+    pub const fn as_const(self) -> RawSlice<T> {
+        RawSlice::new(self.ptr.cast_const(), self.len)
+    }
+
+    /// Get a slice reference from the pointer and length, checking that every element is valid.
+    ///
+    /// # Safety
+    ///
+    /// See [`RawSlice::as_slice`].
+    #[inline]
+    pub unsafe fn as_slice<'a>(self) -> Result<&'a [T], T::Error>
+    where
+        T: CheckBits<Bits: Sized>,
+    {
+        // SAFETY: The caller upholds the same contract.
+        unsafe { self.as_const().as_slice() }
+    }
+
+    /// Get a mutable slice reference from the pointer and length, checking that every element
+    /// is valid.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null and aligned for `T`, even if `len` is zero. It must be valid for
+    /// reads and writes of `len` elements, and the memory must not be accessed through any other
+    /// pointer for the lifetime `'a`.
+    #[inline]
+    pub unsafe fn as_mut_slice<'a>(self) -> Result<&'a mut [T], T::Error>
+    where
+        T: CheckBits<Bits: Sized>,
+    {
+        // SAFETY: The caller ensures that `ptr` is non-null.
+        let bits = from_raw_parts_nonnull::<[T::Bits]>(
+            unsafe { NonNull::new_unchecked(self.ptr.cast()) },
+            self.len,
+        );
+
+        // SAFETY: The caller ensures that `bits` is valid for reads.
+        match unsafe { <[T]>::check_bits(bits) } {
+            // SAFETY: The caller ensures that the memory is uniquely borrowed for `'a`.
+            Ok(mut ptr) => Ok(unsafe { ptr.as_mut() }),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl RawStr {
+    /// Create a [`RawStr`] from a pointer and a length in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new(ptr: *const u8, len: usize) -> Self {
+        Self { ptr, len }
+    }
+
+    /// Create a [`RawStr`] from the address and metadata of a pointer.
+    #[inline]
+    #[must_use]
+    pub const fn from_ptr<P: Pointee<Metadata = usize> + ?Sized>(ptr: *const P) -> Self {
+        let (addr, len) = to_raw_parts(ptr);
+
+        Self::new(addr.cast(), len)
+    }
+
+    /// Create a pointer to a `P` from the pointer and length.
+    #[inline]
+    #[must_use]
+    pub const fn to_ptr<P: Pointee<Metadata = usize> + ?Sized>(self) -> *const P {
+        from_raw_parts(self.ptr.cast(), self.len)
+    }
+
+    /// Get a string slice from the pointer and length, checking that it is valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, even if `len` is zero. It must be valid for reads of `len` bytes,
+    /// and the memory must not be mutated for the lifetime `'a`.
+    #[inline]
+    pub unsafe fn as_str<'a>(self) -> Result<&'a str, Utf8Error> {
+        // SAFETY: The caller ensures that `ptr` is non-null.
+        let bits = from_raw_parts_nonnull::<[u8]>(
+            unsafe { NonNull::new_unchecked(self.ptr.cast_mut().cast()) },
+            self.len,
+        );
+
+        // SAFETY: The caller ensures that `bits` is valid for reads.
+        match unsafe { str::check_bits(bits) } {
+            // SAFETY: The caller ensures that the memory lives for `'a` and is not mutated.
+            Ok(ptr) => Ok(unsafe { ptr.as_ref() }),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<T> From<*const [T]> for RawSlice<T> {
+    #[inline]
+    fn from(ptr: *const [T]) -> Self {
+        Self::from_ptr(ptr)
+    }
+}
+
+impl<T> From<&[T]> for RawSlice<T> {
+    #[inline]
+    fn from(slice: &[T]) -> Self {
+        Self::from_ptr(slice)
+    }
+}
+
+impl<T> From<RawSlice<T>> for *const [T] {
+    #[inline]
+    fn from(raw: RawSlice<T>) -> Self {
+        raw.to_ptr()
+    }
+}
+
+impl<T> From<*mut [T]> for RawSliceMut<T> {
+    #[inline]
+    fn from(ptr: *mut [T]) -> Self {
+        Self::from_ptr(ptr)
+    }
+}
+
+impl<T> From<&mut [T]> for RawSliceMut<T> {
+    #[inline]
+    fn from(slice: &mut [T]) -> Self {
+        Self::from_ptr(slice)
+    }
+}
+
+impl<T> From<RawSliceMut<T>> for *mut [T] {
+    #[inline]
+    fn from(raw: RawSliceMut<T>) -> Self {
+        raw.to_ptr()
+    }
+}
+
+impl<T> From<RawSliceMut<T>> for RawSlice<T> {
+    #[inline]
+    fn from(raw: RawSliceMut<T>) -> Self {
+        raw.as_const()
+    }
+}
+
+impl From<*const str> for RawStr {
+    #[inline]
+    fn from(ptr: *const str) -> Self {
+        Self::from_ptr(ptr)
+    }
+}
+
+impl From<&str> for RawStr {
+    #[inline]
+    fn from(str: &str) -> Self {
+        Self::from_ptr(str)
+    }
+}
+
+impl From<RawStr> for *const str {
+    #[inline]
+    fn from(raw: RawStr) -> Self {
+        raw.to_ptr()
+    }
+}
+
+macro_rules! raw {
+    ($($name:ident$(<$gen:ident>)?),*) => {
+        $(
+            impl$(<$gen>)? Clone for $name$(<$gen>)? {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl$(<$gen>)? Copy for $name$(<$gen>)? {}
+
+            impl$(<$gen>)? fmt::Debug for $name$(<$gen>)? {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_struct(stringify!($name))
+                        .field("ptr", &self.ptr)
+                        .field("len", &self.len)
+                        .finish()
+                }
+            }
+
+            // SAFETY: This is a pointer followed by a `usize`, which have the same size and
+            //         alignment, so there is no padding.
+            unsafe impl$(<$gen>)? NoUninit for $name$(<$gen>)? {}
+
+            unsafe impl$(<$gen>)? Freeze for $name$(<$gen>)? {}
+
+            // SAFETY: A null pointer with a length of zero.
+            unsafe impl$(<$gen>)? Zeroable for $name$(<$gen>)? {}
+        )*
+    };
+}
+
+raw!(RawSlice<T>, RawSliceMut<T>, RawStr);
@@ -0,0 +1,303 @@
+use core::{fmt, hash};
+
+use super::{addr, map_addr_mut, KnownAlign};
+use crate::{Freeze, NoUninit, Zeroable};
+
+/// A pointer to a `T` that stores a tag in the lowest `BITS` bits of its address.
+///
+/// `BITS` must not exceed the number of bits that are always zero due to the alignment of `T`.
+///
+/// Tags are applied with provenance preserving address manipulation, and the pointer metadata
+/// is left untouched, so this works for slices and other [`KnownAlign`] dynamically sized types
+/// as well.
+#[repr(transparent)]
+pub struct TaggedPtr<T: KnownAlign + ?Sized, const BITS: u32> {
+    ptr: *mut T,
+}
+
+impl<T: KnownAlign + ?Sized, const BITS: u32> TaggedPtr<T, BITS> {
+    /// The mask of the bits used for the tag.
+    ///
+    /// Evaluating this fails to compile if `BITS` exceeds the alignment of `T`, so every way of
+    /// creating or reading a [`TaggedPtr`] evaluates it.
+    pub const MASK: usize = {
+        assert!(
+            BITS <= T::ALIGN.trailing_zeros(),
+            "`BITS` exceeds the alignment of `T`"
+        );
+
+        (1 << BITS) - 1
+    };
+
+    /// Create a [`TaggedPtr`] from a pointer and a tag.
+    ///
+    /// Any bits of `tag` outside of [`TaggedPtr::MASK`] are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ptr` is not aligned for `T`, as the low bits of its address would otherwise
+    /// be mixed into the tag.
+    #[track_caller]
+    #[inline]
+    #[must_use]
+    pub fn new(ptr: *mut T, tag: usize) -> Self {
+        assert!(
+            addr(ptr) & Self::MASK == 0,
+            "pointer is not aligned for `T`"
+        );
+
+        Self {
+            ptr: map_addr_mut(ptr, |addr| addr | (tag & Self::MASK)),
+        }
+    }
+
+    /// Create a [`TaggedPtr`] from a pointer that already has a tag applied.
+    #[inline]
+    #[must_use]
+    pub const fn from_tagged(ptr: *mut T) -> Self {
+        const { Self::MASK };
+
+        Self { ptr }
+    }
+
+    /// Get the pointer with the tag applied.
+    #[inline]
+    #[must_use]
+    pub const fn into_tagged(self) -> *mut T {
+        const { Self::MASK };
+
+        self.ptr
+    }
+
+    /// Get the pointer without the tag.
+    #[inline]
+    #[must_use]
+    pub fn ptr(self) -> *mut T {
+        map_addr_mut(self.ptr, |addr| addr & !Self::MASK)
+    }
+
+    /// Get the tag.
+    #[inline]
+    #[must_use]
+    pub fn tag(self) -> usize {
+        addr(self.ptr) & Self::MASK
+    }
+
+    /// Get a [`TaggedPtr`] with the same tag and a different pointer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ptr` is not aligned for `T`.
+    #[track_caller]
+    #[inline]
+    #[must_use]
+    pub fn with_ptr(self, ptr: *mut T) -> Self {
+        Self::new(ptr, self.tag())
+    }
+
+    /// Get a [`TaggedPtr`] with the same pointer and a different tag.
+    ///
+    /// Any bits of `tag` outside of [`TaggedPtr::MASK`] are ignored.
+    #[inline]
+    #[must_use]
+    pub fn with_tag(self, tag: usize) -> Self {
+        Self::new(self.ptr(), tag)
+    }
+}
+
+impl<T: KnownAlign + ?Sized, const BITS: u32> Clone for TaggedPtr<T, BITS> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: KnownAlign + ?Sized, const BITS: u32> Copy for TaggedPtr<T, BITS> {}
+
+impl<T: KnownAlign + ?Sized, const BITS: u32> PartialEq for TaggedPtr<T, BITS> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.ptr, other.ptr)
+    }
+}
+
+impl<T: KnownAlign + ?Sized, const BITS: u32> Eq for TaggedPtr<T, BITS> {}
+
+impl<T: KnownAlign + ?Sized, const BITS: u32> hash::Hash for TaggedPtr<T, BITS> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.ptr.hash(state);
+    }
+}
+
+impl<T: KnownAlign + ?Sized, const BITS: u32> fmt::Debug for TaggedPtr<T, BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaggedPtr")
+            .field("ptr", &self.ptr())
+            .field("tag", &self.tag())
+            .finish()
+    }
+}
+
+// SAFETY: `TaggedPtr` is a `#[repr(transparent)]` wrapper around a pointer.
+
+unsafe impl<T: KnownAlign + ?Sized, const BITS: u32> NoUninit for TaggedPtr<T, BITS> {}
+unsafe impl<T: KnownAlign + ?Sized, const BITS: u32> Freeze for TaggedPtr<T, BITS> {}
+unsafe impl<T: KnownAlign<Metadata: Zeroable> + ?Sized, const BITS: u32> Zeroable
+    for TaggedPtr<T, BITS>
+{
+}
+
+#[cfg(target_has_atomic = "ptr")]
+pub use atomic::*;
+
+#[cfg(target_has_atomic = "ptr")]
+mod atomic {
+    use core::{
+        fmt,
+        marker::PhantomData,
+        sync::atomic::{AtomicPtr, Ordering},
+    };
+
+    use super::TaggedPtr;
+    use crate::{
+        ptr::{from_raw_parts_mut, KnownAlign, Thin},
+        NoUninit, Zeroable,
+    };
+
+    /// An atomic [`TaggedPtr`].
+    ///
+    /// This is only available for [`Thin`] pointers, as there are no stable atomics wide enough
+    /// to hold both an address and its metadata.
+    #[repr(transparent)]
+    pub struct AtomicTaggedPtr<T: KnownAlign + Thin + ?Sized, const BITS: u32> {
+        ptr: AtomicPtr<()>,
+        _marker: PhantomData<*mut T>,
+    }
+
+    impl<T: KnownAlign + Thin + ?Sized, const BITS: u32> AtomicTaggedPtr<T, BITS> {
+        /// Create a new [`AtomicTaggedPtr`].
+        #[inline]
+        #[must_use]
+        pub const fn new(ptr: TaggedPtr<T, BITS>) -> Self {
+            const { TaggedPtr::<T, BITS>::MASK };
+
+            Self {
+                ptr: AtomicPtr::new(ptr.into_tagged().cast()),
+                _marker: PhantomData,
+            }
+        }
+
+        #[inline]
+        fn from_raw(ptr: *mut ()) -> TaggedPtr<T, BITS> {
+            TaggedPtr::from_tagged(from_raw_parts_mut(ptr, ()))
+        }
+
+        #[inline]
+        fn into_raw(ptr: TaggedPtr<T, BITS>) -> *mut () {
+            ptr.into_tagged().cast()
+        }
+
+        /// Consume the [`AtomicTaggedPtr`], returning the [`TaggedPtr`].
+        #[inline]
+        #[must_use]
+        pub fn into_inner(self) -> TaggedPtr<T, BITS> {
+            Self::from_raw(self.ptr.into_inner())
+        }
+
+        /// Load the [`TaggedPtr`].
+        #[inline]
+        #[must_use]
+        pub fn load(&self, order: Ordering) -> TaggedPtr<T, BITS> {
+            Self::from_raw(self.ptr.load(order))
+        }
+
+        /// Store a [`TaggedPtr`].
+        #[inline]
+        pub fn store(&self, ptr: TaggedPtr<T, BITS>, order: Ordering) {
+            self.ptr.store(Self::into_raw(ptr), order);
+        }
+
+        /// Store a [`TaggedPtr`], returning the previous one.
+        #[inline]
+        pub fn swap(&self, ptr: TaggedPtr<T, BITS>, order: Ordering) -> TaggedPtr<T, BITS> {
+            Self::from_raw(self.ptr.swap(Self::into_raw(ptr), order))
+        }
+
+        /// Store `new` if the current value is `current`.
+        ///
+        /// See [`AtomicPtr::compare_exchange`].
+        #[inline]
+        pub fn compare_exchange(
+            &self,
+            current: TaggedPtr<T, BITS>,
+            new: TaggedPtr<T, BITS>,
+            success: Ordering,
+            failure: Ordering,
+        ) -> Result<TaggedPtr<T, BITS>, TaggedPtr<T, BITS>> {
+            match self.ptr.compare_exchange(
+                Self::into_raw(current),
+                Self::into_raw(new),
+                success,
+                failure,
+            ) {
+                Ok(ptr) => Ok(Self::from_raw(ptr)),
+                Err(ptr) => Err(Self::from_raw(ptr)),
+            }
+        }
+
+        /// Store `new` if the current value is `current`, possibly failing spuriously.
+        ///
+        /// See [`AtomicPtr::compare_exchange_weak`].
+        #[inline]
+        pub fn compare_exchange_weak(
+            &self,
+            current: TaggedPtr<T, BITS>,
+            new: TaggedPtr<T, BITS>,
+            success: Ordering,
+            failure: Ordering,
+        ) -> Result<TaggedPtr<T, BITS>, TaggedPtr<T, BITS>> {
+            match self.ptr.compare_exchange_weak(
+                Self::into_raw(current),
+                Self::into_raw(new),
+                success,
+                failure,
+            ) {
+                Ok(ptr) => Ok(Self::from_raw(ptr)),
+                Err(ptr) => Err(Self::from_raw(ptr)),
+            }
+        }
+
+        /// Set the bits of the tag that are set in `tag`, returning the previous value.
+        #[inline]
+        pub fn fetch_or_tag(&self, tag: usize, order: Ordering) -> TaggedPtr<T, BITS> {
+            Self::from_raw(self.ptr.fetch_or(tag & TaggedPtr::<T, BITS>::MASK, order))
+        }
+
+        /// Clear the bits of the tag that are not set in `tag`, returning the previous value.
+        #[inline]
+        pub fn fetch_and_tag(&self, tag: usize, order: Ordering) -> TaggedPtr<T, BITS> {
+            Self::from_raw(self.ptr.fetch_and(tag | !TaggedPtr::<T, BITS>::MASK, order))
+        }
+
+        /// Toggle the bits of the tag that are set in `tag`, returning the previous value.
+        #[inline]
+        pub fn fetch_xor_tag(&self, tag: usize, order: Ordering) -> TaggedPtr<T, BITS> {
+            Self::from_raw(self.ptr.fetch_xor(tag & TaggedPtr::<T, BITS>::MASK, order))
+        }
+    }
+
+    impl<T: KnownAlign + Thin + ?Sized, const BITS: u32> fmt::Debug for AtomicTaggedPtr<T, BITS> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+        }
+    }
+
+    // SAFETY: `AtomicTaggedPtr` is a `#[repr(transparent)]` wrapper around an `AtomicPtr`.
+
+    unsafe impl<T: KnownAlign + Thin + ?Sized, const BITS: u32> Send for AtomicTaggedPtr<T, BITS> {}
+    unsafe impl<T: KnownAlign + Thin + ?Sized, const BITS: u32> Sync for AtomicTaggedPtr<T, BITS> {}
+
+    unsafe impl<T: KnownAlign + Thin + ?Sized, const BITS: u32> NoUninit for AtomicTaggedPtr<T, BITS> {}
+    unsafe impl<T: KnownAlign + Thin + ?Sized, const BITS: u32> Zeroable for AtomicTaggedPtr<T, BITS> {}
+}
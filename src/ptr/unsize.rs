@@ -0,0 +1,77 @@
+use core::ptr::{self, NonNull};
+
+use super::{
+    from_raw_parts, from_raw_parts_mut, from_raw_parts_nonnull, metadata, DynMetadata, Pointee,
+};
+
+/// Trait for sized types that can be unsized into a `Dst`, like how `[T; N]` coerces to `[T]`.
+///
+/// This emulates the unstable `Unsize` trait from `core`, so generic code can unsize pointers
+/// without knowing the concrete types.
+///
+/// # Safety
+///
+/// A pointer to a `Self`, with its metadata replaced by [`Unsize::unsize_meta`], must be a valid
+/// pointer to a `Dst` that covers the same value, with the same size and alignment as `Self`.
+pub unsafe trait Unsize<Dst: Pointee + ?Sized>: Sized {
+    /// Get the metadata of a pointer to `Dst` that was unsized from a `Self`.
+    fn unsize_meta() -> Dst::Metadata;
+}
+
+unsafe impl<T, const N: usize> Unsize<[T]> for [T; N] {
+    #[inline]
+    fn unsize_meta() -> usize {
+        N
+    }
+}
+
+// NOTE: Blanket implementations for trait objects are only possible here, downstream crates
+//       can still implement `Unsize` for their own concrete types.
+
+macro_rules! dyn_unsize {
+    ($($trait:path),*) => {
+        $(
+            dyn_unsize!(@impl [$trait] dyn $trait + 'a);
+            dyn_unsize!(@impl [$trait + Send] dyn $trait + Send + 'a);
+            dyn_unsize!(@impl [$trait + Sync] dyn $trait + Sync + 'a);
+            dyn_unsize!(@impl [$trait + Send + Sync] dyn $trait + Send + Sync + 'a);
+        )*
+    };
+
+    (@impl [$($bound:tt)*] $ty:ty) => {
+        unsafe impl<'a, T: $($bound)* + 'a> Unsize<$ty> for T {
+            #[inline]
+            fn unsize_meta() -> DynMetadata<$ty> {
+                metadata(ptr::null::<T>() as *const $ty)
+            }
+        }
+    };
+}
+
+dyn_unsize!(
+    core::any::Any,
+    core::fmt::Debug,
+    core::fmt::Display,
+    core::error::Error
+);
+
+/// Unsize a pointer to a `T` into a pointer to a `U`.
+#[inline]
+#[must_use]
+pub fn unsize_ptr<T: Unsize<U>, U: Pointee + ?Sized>(ptr: *const T) -> *const U {
+    from_raw_parts(ptr.cast(), T::unsize_meta())
+}
+
+/// Unsize a mutable pointer to a `T` into a mutable pointer to a `U`.
+#[inline]
+#[must_use]
+pub fn unsize_ptr_mut<T: Unsize<U>, U: Pointee + ?Sized>(ptr: *mut T) -> *mut U {
+    from_raw_parts_mut(ptr.cast(), T::unsize_meta())
+}
+
+/// Unsize a [`NonNull`] pointer to a `T` into a [`NonNull`] pointer to a `U`.
+#[inline]
+#[must_use]
+pub fn unsize_nonnull<T: Unsize<U>, U: Pointee + ?Sized>(ptr: NonNull<T>) -> NonNull<U> {
+    from_raw_parts_nonnull(ptr.cast(), T::unsize_meta())
+}
@@ -0,0 +1,209 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+    ptr::{self, NonNull},
+};
+
+use super::{from_raw_parts, provenance, to_raw_parts, DynMetadata, MetadataPosition, Pointee};
+use crate::{Freeze, NoUninit, Zeroable};
+
+/// Trait for pointer metadata that can be stored alongside an address as plain words.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Safety
+///
+/// `Self::Words` must contain exactly the address and the metadata, laid out in the order
+/// given by the [`MetadataPosition`].
+pub unsafe trait MetadataWords: Copy + sealed::Sealed {
+    /// The words of a pointer with this metadata, either `usize` or `[usize; 2]`.
+    type Words: fmt::Debug + Copy + Eq + NoUninit + Zeroable + Freeze;
+
+    #[doc(hidden)]
+    fn into_words(addr: usize, meta: Self, position: MetadataPosition) -> Self::Words;
+
+    #[doc(hidden)]
+    unsafe fn from_words(words: Self::Words, position: MetadataPosition) -> (usize, Self);
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for () {}
+
+unsafe impl MetadataWords for () {
+    type Words = usize;
+
+    #[inline]
+    fn into_words(addr: usize, (): (), _: MetadataPosition) -> usize {
+        addr
+    }
+
+    #[inline]
+    unsafe fn from_words(addr: usize, _: MetadataPosition) -> (usize, ()) {
+        (addr, ())
+    }
+}
+
+/// Lay out an address and a metadata word in the order of `position`.
+#[inline]
+const fn join(addr: usize, meta: usize, position: MetadataPosition) -> [usize; 2] {
+    match position {
+        MetadataPosition::Before => [meta, addr],
+        MetadataPosition::After => [addr, meta],
+    }
+}
+
+/// Split words into an address and a metadata word in the order of `position`.
+#[inline]
+const fn split(words: [usize; 2], position: MetadataPosition) -> (usize, usize) {
+    match position {
+        MetadataPosition::Before => (words[1], words[0]),
+        MetadataPosition::After => (words[0], words[1]),
+    }
+}
+
+impl sealed::Sealed for usize {}
+
+unsafe impl MetadataWords for usize {
+    type Words = [usize; 2];
+
+    #[inline]
+    fn into_words(addr: usize, len: usize, position: MetadataPosition) -> [usize; 2] {
+        join(addr, len, position)
+    }
+
+    #[inline]
+    unsafe fn from_words(words: [usize; 2], position: MetadataPosition) -> (usize, usize) {
+        split(words, position)
+    }
+}
+
+impl<Dyn: ?Sized> sealed::Sealed for DynMetadata<Dyn> {}
+
+unsafe impl<Dyn: ?Sized> MetadataWords for DynMetadata<Dyn> {
+    type Words = [usize; 2];
+
+    #[inline]
+    fn into_words(addr: usize, meta: Self, position: MetadataPosition) -> [usize; 2] {
+        join(addr, meta.vtable.as_ptr().expose_provenance(), position)
+    }
+
+    #[inline]
+    unsafe fn from_words(words: [usize; 2], position: MetadataPosition) -> (usize, Self) {
+        let (addr, vtable) = split(words, position);
+
+        let meta = DynMetadata {
+            // SAFETY: The caller ensures that the words came from `into_words`, which exposed
+            //         the provenance of the vtable, and vtables are never null.
+            vtable: unsafe { NonNull::new_unchecked(ptr::with_exposed_provenance_mut(vtable)) },
+            _marker: PhantomData,
+        };
+
+        (addr, meta)
+    }
+}
+
+/// A pointer to a `T` that can be converted to and from plain words.
+///
+/// Pointers to [`Thin`](super::Thin) types are a single `usize`, while any other pointer is
+/// a `[usize; 2]`, with the address and metadata in the order given by its [`MetadataPosition`].
+///
+/// The provenance of the address is exposed when converting into words, and recovered when
+/// converting back.
+#[repr(transparent)]
+pub struct RawFatPtr<T: Pointee + ?Sized> {
+    ptr: *const T,
+}
+
+impl<T: Pointee + ?Sized> RawFatPtr<T> {
+    /// Create a [`RawFatPtr`] from a pointer.
+    #[inline]
+    #[must_use]
+    pub const fn new(ptr: *const T) -> Self {
+        Self { ptr }
+    }
+
+    /// Get the pointer.
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(self) -> *const T {
+        self.ptr
+    }
+
+    /// Get the pointer as a mutable pointer.
+    #[inline]
+    #[must_use]
+    pub const fn as_mut_ptr(self) -> *mut T {
+        self.ptr.cast_mut()
+    }
+
+    /// Convert the pointer into words, exposing its provenance.
+    #[inline]
+    #[must_use]
+    pub fn into_words(self) -> <T::Metadata as MetadataWords>::Words
+    where
+        T::Metadata: MetadataWords,
+    {
+        let (_, meta) = to_raw_parts(self.ptr);
+
+        MetadataWords::into_words(
+            provenance::expose_provenance(self.ptr),
+            meta,
+            T::METADATA_POSITION,
+        )
+    }
+
+    /// Create a pointer from words, recovering its exposed provenance.
+    ///
+    /// # Safety
+    ///
+    /// `words` must have been returned by [`RawFatPtr::into_words`] for a pointer to a `T`.
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_words(words: <T::Metadata as MetadataWords>::Words) -> Self
+    where
+        T::Metadata: MetadataWords,
+    {
+        // SAFETY: The caller ensures that `words` came from `into_words`.
+        let (addr, meta) = unsafe { MetadataWords::from_words(words, T::METADATA_POSITION) };
+
+        Self::new(from_raw_parts(ptr::with_exposed_provenance(addr), meta))
+    }
+}
+
+impl<T: Pointee + ?Sized> Clone for RawFatPtr<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Pointee + ?Sized> Copy for RawFatPtr<T> {}
+
+impl<T: Pointee + ?Sized> fmt::Debug for RawFatPtr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RawFatPtr").field(&self.ptr).finish()
+    }
+}
+
+impl<T: Pointee + ?Sized> From<*const T> for RawFatPtr<T> {
+    #[inline]
+    fn from(ptr: *const T) -> Self {
+        Self::new(ptr)
+    }
+}
+
+impl<T: Pointee + ?Sized> From<*mut T> for RawFatPtr<T> {
+    #[inline]
+    fn from(ptr: *mut T) -> Self {
+        Self::new(ptr)
+    }
+}
+
+// SAFETY: `RawFatPtr` is a `#[repr(transparent)]` wrapper around a pointer.
+
+unsafe impl<T: Pointee + ?Sized> NoUninit for RawFatPtr<T> {}
+unsafe impl<T: Pointee + ?Sized> Freeze for RawFatPtr<T> {}
+unsafe impl<T: Pointee<Metadata: Zeroable> + ?Sized> Zeroable for RawFatPtr<T> {}
@@ -0,0 +1,286 @@
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error},
+    boxed::Box,
+};
+use core::{
+    alloc::{Layout, LayoutError},
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+};
+
+use crate::{
+    ptr::{from_raw_parts_nonnull, metadata, DstLayout, Unsize},
+    Freeze, ZeroableInOption,
+};
+
+/// An owning pointer to a `T` that is a single word, even when `T` is dynamically sized.
+///
+/// The pointer metadata is stored in the allocation, directly before the value.
+#[repr(transparent)]
+pub struct ThinBox<T: DstLayout + ?Sized> {
+    /// Pointer to the value, which is preceded by its metadata.
+    ptr: NonNull<u8>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: DstLayout> ThinBox<T> {
+    /// Move a value into a new [`ThinBox`].
+    #[inline]
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        let this = Self::allocate(metadata(ptr::from_ref(&value)), Layout::new::<T>());
+
+        // SAFETY: The allocation has room for a `T`.
+        unsafe { this.ptr.cast::<T>().write(value) };
+
+        this
+    }
+}
+
+impl<T: DstLayout + ?Sized> ThinBox<T> {
+    /// Move a value into a new [`ThinBox`], unsizing it into a `T`.
+    #[inline]
+    #[must_use]
+    pub fn new_unsize<S: Unsize<T>>(value: S) -> Self {
+        // `Unsize` guarantees that the unsized value has the same layout as the `S`.
+        let this = Self::allocate(S::unsize_meta(), Layout::new::<S>());
+
+        // SAFETY: The allocation has room for a `T` unsized from an `S`, which covers the `S`.
+        unsafe { this.ptr.cast::<S>().write(value) };
+
+        this
+    }
+
+    /// Get the layout of the allocation for a value with the given layout, and the offset of
+    /// the value within it.
+    ///
+    /// The layout of the value is always taken from a value, rather than from its metadata, as
+    /// vtables are not guaranteed to be readable.
+    #[inline]
+    fn layout(value: Layout) -> Result<(Layout, usize), LayoutError> {
+        // Pad the header to the alignment of the value, so the metadata ends where the value starts.
+        let header = Layout::new::<T::Metadata>()
+            .align_to(value.align())?
+            .pad_to_align();
+
+        match header.extend(value) {
+            Ok((layout, offset)) => Ok((layout.pad_to_align(), offset)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Allocate room for a `T` with the given metadata and layout, and write the metadata.
+    ///
+    /// The value is left uninitialized.
+    fn allocate(meta: T::Metadata, value: Layout) -> Self {
+        let (layout, offset) = match Self::layout(value) {
+            Ok(layout) => layout,
+            Err(_) => panic!("`ThinBox` layout overflowed"),
+        };
+
+        let ptr = if layout.size() == 0 {
+            // SAFETY: The metadata must be zero-sized, so the value is all we need to align for.
+            unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(layout.align())) }
+        } else {
+            // SAFETY: The layout is not zero-sized.
+            match NonNull::new(unsafe { alloc(layout) }) {
+                // SAFETY: The offset is within the allocation.
+                Some(base) => unsafe { base.add(offset) },
+                None => handle_alloc_error(layout),
+            }
+        };
+
+        // SAFETY: The metadata fits, and is aligned directly before the value.
+        unsafe { Self::header(ptr).write(meta) };
+
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get a pointer to the metadata that precedes the value at `ptr`.
+    #[inline]
+    const unsafe fn header(ptr: NonNull<u8>) -> NonNull<T::Metadata> {
+        // SAFETY: The caller ensures that `ptr` is preceded by the metadata.
+        unsafe { ptr.sub(size_of::<T::Metadata>()).cast() }
+    }
+
+    /// Get the pointer metadata of the value.
+    #[inline]
+    #[must_use]
+    pub fn metadata(&self) -> T::Metadata {
+        // SAFETY: The metadata is always written before the value.
+        unsafe { Self::header(self.ptr).read() }
+    }
+
+    /// Get a pointer to the value.
+    #[inline]
+    #[must_use]
+    pub fn as_ptr(&self) -> NonNull<T> {
+        from_raw_parts_nonnull(self.ptr.cast(), self.metadata())
+    }
+}
+
+impl<T: DstLayout + ?Sized> Drop for ThinBox<T> {
+    fn drop(&mut self) {
+        let value = Layout::for_value::<T>(self);
+
+        // SAFETY: We own the value.
+        unsafe { ptr::drop_in_place(self.as_ptr().as_ptr()) };
+
+        // SAFETY: This layout was already computed when allocating.
+        let (layout, offset) = unsafe { Self::layout(value).unwrap_unchecked() };
+
+        if layout.size() != 0 {
+            // SAFETY: We allocated this with the same layout.
+            unsafe { dealloc(self.ptr.as_ptr().sub(offset), layout) };
+        }
+    }
+}
+
+impl<T: DstLayout + ?Sized> Deref for ThinBox<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: We own the value.
+        unsafe { self.as_ptr().as_ref() }
+    }
+}
+
+impl<T: DstLayout + ?Sized> DerefMut for ThinBox<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: We own the value, and have a unique reference to it.
+        unsafe { self.as_ptr().as_mut() }
+    }
+}
+
+impl<T: DstLayout + ?Sized> From<Box<T>> for ThinBox<T> {
+    fn from(value: Box<T>) -> Self {
+        let layout = Layout::for_value(&*value);
+        let this = Self::allocate(metadata(&*value), layout);
+
+        let src = Box::into_raw(value);
+
+        // SAFETY: Both allocations have room for the value, and we move it out of the `Box`
+        //         before freeing the `Box` without dropping it.
+        unsafe {
+            ptr::copy_nonoverlapping(src.cast::<u8>(), this.ptr.as_ptr(), layout.size());
+
+            if layout.size() != 0 {
+                dealloc(src.cast(), layout);
+            }
+        }
+
+        this
+    }
+}
+
+impl<T: DstLayout + ?Sized + fmt::Debug> fmt::Debug for ThinBox<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: DstLayout + ?Sized + fmt::Display> fmt::Display for ThinBox<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+// SAFETY: `ThinBox` owns its value, like a `Box`.
+
+unsafe impl<T: DstLayout + ?Sized + Send> Send for ThinBox<T> {}
+unsafe impl<T: DstLayout + ?Sized + Sync> Sync for ThinBox<T> {}
+
+// SAFETY: `ThinBox` is a `#[repr(transparent)]` wrapper around a `NonNull`.
+
+unsafe impl<T: DstLayout + ?Sized> ZeroableInOption for ThinBox<T> {}
+unsafe impl<T: DstLayout + ?Sized> Freeze for ThinBox<T> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format, rc::Rc, string::String, vec};
+    use core::fmt::Debug;
+
+    use super::ThinBox;
+
+    #[repr(align(64))]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct OverAligned(u8);
+
+    #[test]
+    fn sized() {
+        let mut value = ThinBox::new(5_u64);
+        *value += 1;
+
+        assert_eq!(*value, 6);
+        assert_eq!(size_of::<ThinBox<u64>>(), size_of::<usize>());
+    }
+
+    #[test]
+    fn slice() {
+        let mut value = ThinBox::<[u32]>::new_unsize([1, 2, 3]);
+        value[1] = 5;
+
+        assert_eq!(value.metadata(), 3);
+        assert_eq!(&*value, &[1, 5, 3]);
+        assert_eq!(size_of::<ThinBox<[u32]>>(), size_of::<usize>());
+    }
+
+    #[test]
+    fn zero_sized_slice() {
+        let value = ThinBox::<[()]>::new_unsize([(); 4]);
+        assert_eq!(value.len(), 4);
+
+        let empty = ThinBox::<[u8]>::new_unsize([]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn over_aligned_slice() {
+        let value = ThinBox::<[OverAligned]>::new_unsize([OverAligned(1), OverAligned(2)]);
+
+        assert!(value.as_ptr().addr().get().is_multiple_of(64));
+        assert_eq!(&*value, &[OverAligned(1), OverAligned(2)]);
+    }
+
+    #[test]
+    fn str() {
+        let value = ThinBox::<str>::from(Box::<str>::from("hello"));
+
+        assert_eq!(&*value, "hello");
+        assert_eq!(format!("{value}"), "hello");
+    }
+
+    #[test]
+    fn from_box_drops_once() {
+        let counter = Rc::new(());
+        let value =
+            ThinBox::<[Rc<()>]>::from(vec![counter.clone(), counter.clone()].into_boxed_slice());
+
+        assert_eq!(Rc::strong_count(&counter), 3);
+        drop(value);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn dyn_debug() {
+        let counter = Rc::new(());
+        let value = ThinBox::<dyn Debug>::new_unsize(counter.clone());
+
+        assert_eq!(format!("{value:?}"), "()");
+        assert_eq!(Rc::strong_count(&counter), 2);
+        drop(value);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let value = ThinBox::<dyn Debug>::new_unsize(String::from("hi"));
+        assert_eq!(format!("{value:?}"), "\"hi\"");
+    }
+}
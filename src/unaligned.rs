@@ -7,6 +7,10 @@ use core::{
 use crate::{Freeze, NoUninit, Zeroable};
 
 /// Trait for types that have no alignment requirement.
+///
+/// # Safety
+///
+/// `Self` must have an alignment of `1`.
 pub unsafe trait Unaligned {}
 
 unsafe impl<T: Unaligned> Unaligned for [T] {}
@@ -46,8 +50,47 @@ unsafe impl Unaligned for str {}
 unsafe impl Unaligned for core::cmp::Ordering {}
 unsafe impl Unaligned for core::marker::PhantomPinned {}
 
+macro_rules! unaligned_tuple {
+    (
+        $(
+            (
+                $( $name:ident
+                    $( : ( $( $rest:tt )* ) )?
+                ),*
+            )
+        ),* $(,)?
+    ) => {
+        $(
+            unsafe impl<$($name: Unaligned $( $($rest)* )?),*> Unaligned for ($($name,)*) {}
+        )*
+    };
+}
+
+unaligned_tuple!(
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7, T8: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6, T7: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5, T6: (+ ?Sized)),
+    (T0, T1, T2, T3, T4, T5: (+ ?Sized)),
+    (T0, T1, T2, T3, T4: (+ ?Sized)),
+    (T0, T1, T2, T3: (+ ?Sized)),
+    (T0, T1, T2: (+ ?Sized)),
+    (T0, T1: (+ ?Sized)),
+    (T0: (+ ?Sized)),
+);
+
 /// Trait for types that have no alignment requirement when wrapped
 /// in an [`Option`].
+///
+/// # Safety
+///
+/// `Option<Self>` must have an alignment of `1`.
 pub unsafe trait UnalignedInOption: Sized {}
 
 unsafe impl<T: UnalignedInOption> Unaligned for Option<T> {}
@@ -55,6 +98,9 @@ unsafe impl<T: UnalignedInOption> Unaligned for Option<T> {}
 unsafe impl UnalignedInOption for core::num::NonZero<u8> {}
 unsafe impl UnalignedInOption for core::num::NonZero<i8> {}
 
+unsafe impl UnalignedInOption for bool {}
+unsafe impl UnalignedInOption for core::cmp::Ordering {}
+
 unsafe impl<T: UnalignedInOption> UnalignedInOption for core::num::Saturating<T> {}
 unsafe impl<T: UnalignedInOption> UnalignedInOption for core::num::Wrapping<T> {}
 
@@ -91,12 +137,18 @@ impl<T> Unalign<T> {
         &raw mut self.0
     }
 
+    /// # Safety
+    ///
+    /// The inner value must be suitably aligned for `T`.
     #[inline]
     #[must_use]
     pub const unsafe fn as_ref_unchecked(&self) -> &T {
         unsafe { &*self.as_ptr() }
     }
 
+    /// # Safety
+    ///
+    /// The inner value must be suitably aligned for `T`.
     #[inline]
     #[must_use]
     pub const unsafe fn as_mut_unchecked(&mut self) -> &mut T {
@@ -136,7 +188,6 @@ impl<T> Unalign<T> {
     }
 
     #[inline]
-    #[must_use]
     pub fn set(&mut self, value: T) {
         self.0 = value;
     }
@@ -229,11 +280,6 @@ impl<T: Unaligned + PartialEq> PartialEq for Unalign<T> {
     fn eq(&self, other: &Self) -> bool {
         self.deref().eq(other)
     }
-
-    #[inline]
-    fn ne(&self, other: &Self) -> bool {
-        self.deref().ne(other)
-    }
 }
 
 impl<T: Unaligned + Eq> Eq for Unalign<T> {}
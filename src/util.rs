@@ -3,7 +3,6 @@
 use core::{mem::ManuallyDrop, ptr, slice};
 
 #[inline(always)]
-#[must_use]
 pub const unsafe fn memset<T: ?Sized>(dst: &mut T, val: u8) {
     let size = size_of_val(dst);
 
@@ -0,0 +1,134 @@
+use core::{fmt, hash, marker::PhantomData, ops::Deref, ptr};
+
+use crate::{ptr as legume_ptr, CheckBits, Freeze};
+
+/// A reference to some `T` that is known to have passed [`CheckBits`] validation.
+///
+/// It keeps the borrow of the original bits, so APIs can require a `Validated<T>` as
+/// proof that validation already happened instead of checking the bits again.
+#[repr(transparent)]
+pub struct Validated<'a, T: CheckBits + ?Sized> {
+    bits: &'a T::Bits,
+    /// This hands out `&'a T`, so the auto traits must follow `T` rather than `T::Bits`.
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T: CheckBits + ?Sized> Validated<'a, T> {
+    /// Validate `bits` as a `T`.
+    #[track_caller]
+    #[inline]
+    pub fn new(bits: &'a T::Bits) -> Result<Validated<'a, T>, T::Error>
+    where
+        T: Freeze,
+        T::Bits: Freeze,
+    {
+        match T::cast_ref(bits) {
+            // SAFETY: `cast_ref` just validated `bits`.
+            Ok(_) => Ok(unsafe { Validated::new_unchecked(bits) }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Wrap `bits` without validating it.
+    ///
+    /// # Safety
+    ///
+    /// `bits` must be a valid `T`, and `T` must not be mutated through interior
+    /// mutability for the lifetime `'a`.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked(bits: &'a T::Bits) -> Validated<'a, T> {
+        Validated {
+            bits,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the original bits that were validated.
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> &'a T::Bits {
+        self.bits
+    }
+
+    /// Get the validated `T`.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> &'a T {
+        let ptr = ptr::from_ref(self.bits);
+        let ptr = legume_ptr::from_raw_parts::<T>(ptr.cast(), legume_ptr::metadata(ptr));
+
+        // SAFETY: `bits` was validated upon construction, `T` has the same layout as `T::Bits`,
+        //         and both pointers share the same metadata.
+        unsafe { &*ptr }
+    }
+}
+
+impl<T: CheckBits + ?Sized> Clone for Validated<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: CheckBits + ?Sized> Copy for Validated<'_, T> {}
+
+impl<T: CheckBits + ?Sized> Deref for Validated<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T: CheckBits + ?Sized> AsRef<T> for Validated<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T: CheckBits + PartialEq + ?Sized> PartialEq for Validated<'_, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.get().eq(other.get())
+    }
+}
+
+impl<T: CheckBits + Eq + ?Sized> Eq for Validated<'_, T> {}
+
+impl<T: CheckBits + PartialOrd + ?Sized> PartialOrd for Validated<'_, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.get().partial_cmp(other.get())
+    }
+}
+
+impl<T: CheckBits + Ord + ?Sized> Ord for Validated<'_, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.get().cmp(other.get())
+    }
+}
+
+impl<T: CheckBits + hash::Hash + ?Sized> hash::Hash for Validated<'_, T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.get().hash(state);
+    }
+}
+
+impl<T: CheckBits + fmt::Debug + ?Sized> fmt::Debug for Validated<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+impl<T: CheckBits + fmt::Display + ?Sized> fmt::Display for Validated<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{macros::impl_for_atomic, ptr::Pointee, util};
+use crate::{
+    macros::{impl_for_atomic, impl_for_fn_ptr, impl_for_simd},
+    ptr::Pointee,
+    util,
+};
 
 // #[doc(inline)]
 // pub use crate::zeroable_in_option::*;
@@ -21,7 +25,9 @@ pub const fn fill_zeroes<T: Zeroable + ?Sized>(dst: &mut T) -> &mut T {
 
 /// Trait for types that can be filled with all zeroes.
 ///
-/// Implementors must be inhabited.
+/// # Safety
+///
+/// Implementors must be inhabited, and the all zeroes bit pattern must be a valid `Self`.
 pub unsafe trait Zeroable {}
 
 unsafe impl<T: Zeroable> Zeroable for [T] {}
@@ -51,6 +57,7 @@ macro_rules! zeroable {
     };
 }
 
+// NOTE: The `core::ffi::c_*` integer and float types are aliases of these, and so are covered too.
 zeroable!(u8, u16, u32, u64, u128, usize);
 zeroable!(i8, i16, i32, i64, i128, isize);
 zeroable!(f32, f64);
@@ -60,6 +67,12 @@ zeroable!(str);
 zeroable!(core::cmp::Ordering);
 zeroable!(core::marker::PhantomPinned);
 
+// SAFETY: A `Duration` is a `u64` of seconds, and a `u32` of nanoseconds that is less
+//         than one second, both of which are valid when zero.
+zeroable!(core::time::Duration);
+
+// NOTE: `core::convert::Infallible` is uninhabited, and so is not `Zeroable`.
+
 macro_rules! zeroable_tuple {
     (
         $(
@@ -97,6 +110,7 @@ zeroable_tuple!(
 );
 
 impl_for_atomic!(unsafe impl Zeroable);
+impl_for_simd!(zeroable);
 
 /// Trait that is automatically implemented for all [`Zeroable`] types,
 /// providing some
@@ -122,6 +136,10 @@ pub trait ZeroableExt: Zeroable {
 impl<T: Zeroable + ?Sized> ZeroableExt for T {}
 
 /// Trait for types that when wrapped in an [`Option`] are zeroable.
+///
+/// # Safety
+///
+/// The all zeroes bit pattern must be a valid `Option<Self>`.
 pub unsafe trait ZeroableInOption: Sized {}
 
 unsafe impl<T: ZeroableInOption> Zeroable for Option<T> {}
@@ -147,3 +165,8 @@ unsafe impl<T: ZeroableInOption> ZeroableInOption for core::cmp::Reverse<T> {}
 unsafe impl<T: ?Sized> ZeroableInOption for core::ptr::NonNull<T> {}
 unsafe impl<T: ?Sized> ZeroableInOption for &T {}
 unsafe impl<T: ?Sized> ZeroableInOption for &mut T {}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: ?Sized> ZeroableInOption for alloc::boxed::Box<T> {}
+
+impl_for_fn_ptr!(unsafe impl ZeroableInOption);